@@ -0,0 +1,73 @@
+#![feature(test)]
+
+extern crate algorithm;
+extern crate quickersort;
+extern crate rand;
+extern crate test;
+
+use algorithm::sort::intro::intro_sort;
+use algorithm::sort::quick::quick_sort;
+use rand::Rng;
+use test::Bencher;
+
+const LEN: usize = 10000;
+
+fn random() -> Vec<u64> {
+    let mut rng = rand::thread_rng();
+    (0..LEN).map(|_| rng.gen::<u64>()).collect()
+}
+
+fn sorted() -> Vec<u64> {
+    (0..LEN as u64).collect()
+}
+
+fn reversed() -> Vec<u64> {
+    (0..LEN as u64).rev().collect()
+}
+
+fn few_unique() -> Vec<u64> {
+    let mut rng = rand::thread_rng();
+    (0..LEN).map(|_| rng.gen_range(0, 8)).collect()
+}
+
+fn organ_pipe() -> Vec<u64> {
+    (0..LEN as u64 / 2).chain((0..LEN as u64 / 2).rev()).collect()
+}
+
+macro_rules! bench_sort {
+    ($name:ident, $gen:ident, $sort:expr) => {
+        #[bench]
+        fn $name(b: &mut Bencher) {
+            let source = $gen();
+            b.iter(|| {
+                let mut v = source.clone();
+                $sort(&mut v[..]);
+                v
+            });
+        }
+    };
+}
+
+bench_sort!(intro_sort_random, random, intro_sort);
+bench_sort!(intro_sort_sorted, sorted, intro_sort);
+bench_sort!(intro_sort_reversed, reversed, intro_sort);
+bench_sort!(intro_sort_few_unique, few_unique, intro_sort);
+bench_sort!(intro_sort_organ_pipe, organ_pipe, intro_sort);
+
+bench_sort!(quick_sort_random, random, quick_sort);
+bench_sort!(quick_sort_sorted, sorted, quick_sort);
+bench_sort!(quick_sort_reversed, reversed, quick_sort);
+bench_sort!(quick_sort_few_unique, few_unique, quick_sort);
+bench_sort!(quick_sort_organ_pipe, organ_pipe, quick_sort);
+
+bench_sort!(quickersort_random, random, quickersort::sort);
+bench_sort!(quickersort_sorted, sorted, quickersort::sort);
+bench_sort!(quickersort_reversed, reversed, quickersort::sort);
+bench_sort!(quickersort_few_unique, few_unique, quickersort::sort);
+bench_sort!(quickersort_organ_pipe, organ_pipe, quickersort::sort);
+
+bench_sort!(sort_unstable_random, random, <[u64]>::sort_unstable);
+bench_sort!(sort_unstable_sorted, sorted, <[u64]>::sort_unstable);
+bench_sort!(sort_unstable_reversed, reversed, <[u64]>::sort_unstable);
+bench_sort!(sort_unstable_few_unique, few_unique, <[u64]>::sort_unstable);
+bench_sort!(sort_unstable_organ_pipe, organ_pipe, <[u64]>::sort_unstable);
@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::slice;
+
+use graph::storage::{AdjacencyList, Storage};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    Directed,
+    Undirected,
+}
+
+/// Graph with nodes of type `N`, known by their value, and edges weighted
+/// by `E` (`()` for unweighted graphs), at most one between two nodes.
+///
+/// Edges are kept by `S`, an `AdjacencyList` by default or an
+/// `AdjacencyMatrix`.
+///
+/// # Example
+/// ```
+/// use algorithm::graph::adjacency::Graph;
+///
+/// let mut graph = Graph::undirected();
+/// graph.add_edge("a", "b", 3);
+/// graph.add_edge("b", "c", 5);
+/// assert_eq!(graph.edge(&"c", &"b"), Some(&5));
+///
+/// let mut neighbors: Vec<_> = graph.neighbors(&"b").collect();
+/// neighbors.sort();
+/// assert_eq!(neighbors, vec![(&"a", &3), (&"c", &5)]);
+///
+/// graph.remove_node(&"b");
+/// assert_eq!(graph.edge_count(), 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Graph<N, E, S = AdjacencyList<E>> {
+    direction: Direction,
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    edge_count: usize,
+    storage: S,
+    // `E` only appears in `S`
+    weight: ::std::marker::PhantomData<E>,
+}
+
+impl<N: Hash + Eq + Clone, E> Graph<N, E> {
+    pub fn directed() -> Self {
+        Graph::new(Direction::Directed)
+    }
+
+    pub fn undirected() -> Self {
+        Graph::new(Direction::Undirected)
+    }
+}
+
+impl<N, E, S> Graph<N, E, S>
+where
+    N: Hash + Eq + Clone,
+    S: Storage<E>,
+{
+    /// An empty graph on any storage, e.g.
+    /// `Graph::<_, _, AdjacencyMatrix<_>>::new(Direction::Directed)`.
+    pub fn new(direction: Direction) -> Self {
+        Graph {
+            direction,
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            edge_count: 0,
+            storage: S::new(),
+            weight: ::std::marker::PhantomData,
+        }
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.direction == Direction::Directed
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Number of edges, counting the two directions of an undirected edge
+    /// once.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    pub fn contains_node(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    /// Add `node` unless it is in the graph already, returning whether it
+    /// was added.
+    pub fn add_node(&mut self, node: N) -> bool {
+        if self.indices.contains_key(&node) {
+            return false;
+        }
+        self.index_or_insert(node);
+        true
+    }
+
+    /// Remove `node` and all its edges, returning whether it was there.
+    pub fn remove_node(&mut self, node: &N) -> bool {
+        let index = match self.indices.get(node) {
+            Some(&index) => index,
+            None => return false,
+        };
+
+        let targets: Vec<usize> = self.storage.neighbors(index).map(|(t, _)| t).collect();
+        for target in targets {
+            self.remove_edge_at(index, target);
+        }
+        if self.is_directed() {
+            for source in 0..self.nodes.len() {
+                if self.storage.edge(source, index).is_some() {
+                    self.remove_edge_at(source, index);
+                }
+            }
+        }
+
+        // the last node takes the place of the removed one
+        self.storage.remove_node(index);
+        self.indices.remove(node);
+        self.nodes.swap_remove(index);
+        if index < self.nodes.len() {
+            self.indices.insert(self.nodes[index].clone(), index);
+        }
+        true
+    }
+
+    /// Set the edge `from -> to` (and `to -> from` in an undirected graph)
+    /// to `weight`, adding the nodes as needed. Returns the old weight.
+    pub fn add_edge(&mut self, from: N, to: N, weight: E) -> Option<E>
+    where
+        E: Clone,
+    {
+        let from = self.index_or_insert(from);
+        let to = self.index_or_insert(to);
+        if !self.is_directed() && from != to {
+            self.storage.set_edge(to, from, weight.clone());
+        }
+        let old = self.storage.set_edge(from, to, weight);
+        if old.is_none() {
+            self.edge_count += 1;
+        }
+        old
+    }
+
+    /// Remove the edge `from -> to` (both directions in an undirected
+    /// graph), returning its weight.
+    pub fn remove_edge(&mut self, from: &N, to: &N) -> Option<E> {
+        match (self.indices.get(from), self.indices.get(to)) {
+            (Some(&from), Some(&to)) => self.remove_edge_at(from, to),
+            _ => None,
+        }
+    }
+
+    pub fn edge(&self, from: &N, to: &N) -> Option<&E> {
+        match (self.indices.get(from), self.indices.get(to)) {
+            (Some(&from), Some(&to)) => self.storage.edge(from, to),
+            _ => None,
+        }
+    }
+
+    pub fn contains_edge(&self, from: &N, to: &N) -> bool {
+        self.edge(from, to).is_some()
+    }
+
+    pub fn nodes<'a>(&'a self) -> slice::Iter<'a, N> {
+        self.nodes.iter()
+    }
+
+    /// The nodes `node` has an edge to, with the weights. Empty if `node`
+    /// is not in the graph.
+    pub fn neighbors<'a>(&'a self, node: &N) -> Neighbors<'a, N, E> {
+        let targets = match self.indices.get(node) {
+            Some(&index) => self.storage.neighbors(index),
+            None => Box::new(None.into_iter()),
+        };
+        Neighbors {
+            nodes: &self.nodes,
+            targets,
+        }
+    }
+
+    /// Every edge as `(from, to, weight)`; an undirected edge is listed in
+    /// one direction only.
+    pub fn edges(&self) -> Vec<(&N, &N, &E)> {
+        let mut edges = Vec::with_capacity(self.edge_count);
+        for from in 0..self.nodes.len() {
+            for (to, weight) in self.storage.neighbors(from) {
+                if self.is_directed() || from <= to {
+                    edges.push((&self.nodes[from], &self.nodes[to], weight));
+                }
+            }
+        }
+        edges
+    }
+
+    /// The graph as a map from each node to the nodes it has an edge to,
+    /// the shape taken by `search::breadth`.
+    pub fn to_adjacency_map(&self) -> HashMap<N, Vec<N>> {
+        self.nodes
+            .iter()
+            .map(|node| {
+                (
+                    node.clone(),
+                    self.neighbors(node).map(|(t, _)| t.clone()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    /// The graph as a map from each node to its neighbours and the weights,
+    /// the shape taken by `search::dijkstra::search`.
+    pub fn to_weighted_map(&self) -> HashMap<N, HashMap<N, E>>
+    where
+        E: Clone,
+    {
+        self.nodes
+            .iter()
+            .map(|node| {
+                let targets = self
+                    .neighbors(node)
+                    .map(|(t, weight)| (t.clone(), weight.clone()))
+                    .collect();
+                (node.clone(), targets)
+            })
+            .collect()
+    }
+
+    fn index_or_insert(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.storage.add_node();
+        self.nodes.push(node.clone());
+        self.indices.insert(node, index);
+        index
+    }
+
+    fn remove_edge_at(&mut self, from: usize, to: usize) -> Option<E> {
+        if !self.is_directed() && from != to {
+            self.storage.remove_edge(to, from);
+        }
+        let weight = self.storage.remove_edge(from, to);
+        if weight.is_some() {
+            self.edge_count -= 1;
+        }
+        weight
+    }
+}
+
+/// Iterator over the neighbours of a node, see `Graph::neighbors`.
+pub struct Neighbors<'a, N: 'a, E: 'a> {
+    nodes: &'a [N],
+    targets: Box<dyn Iterator<Item = (usize, &'a E)> + 'a>,
+}
+
+impl<'a, N, E> Iterator for Neighbors<'a, N, E> {
+    type Item = (&'a N, &'a E);
+
+    fn next(&mut self) -> Option<(&'a N, &'a E)> {
+        self.targets
+            .next()
+            .map(|(t, weight)| (&self.nodes[t], weight))
+    }
+}
+
+/// Directed, unweighted graph of the map shape taken by `search::breadth`:
+/// each node to the nodes it has an edge to.
+impl<'a, N, S> From<&'a HashMap<N, Vec<N>>> for Graph<N, (), S>
+where
+    N: Hash + Eq + Clone,
+    S: Storage<()>,
+{
+    fn from(map: &'a HashMap<N, Vec<N>>) -> Self {
+        let mut graph = Graph::new(Direction::Directed);
+        for (node, targets) in map {
+            graph.add_node(node.clone());
+            for target in targets {
+                graph.add_edge(node.clone(), target.clone(), ());
+            }
+        }
+        graph
+    }
+}
+
+/// Directed, weighted graph of the map shape taken by
+/// `search::dijkstra::search`: each node to its neighbours and the weights.
+impl<'a, N, E, S> From<&'a HashMap<N, HashMap<N, E>>> for Graph<N, E, S>
+where
+    N: Hash + Eq + Clone,
+    E: Clone,
+    S: Storage<E>,
+{
+    fn from(map: &'a HashMap<N, HashMap<N, E>>) -> Self {
+        let mut graph = Graph::new(Direction::Directed);
+        for (node, targets) in map {
+            graph.add_node(node.clone());
+            for (target, weight) in targets {
+                graph.add_edge(node.clone(), target.clone(), weight.clone());
+            }
+        }
+        graph
+    }
+}
+
+/// Anything a search can walk: a `Graph`, or a map from each node to the
+/// nodes it has an edge to.
+pub trait Adjacent<N> {
+    /// The nodes `node` has an edge to, none if it is unknown.
+    fn adjacent<'a>(&'a self, node: &N) -> Box<dyn Iterator<Item = &'a N> + 'a>;
+}
+
+impl<N: Hash + Eq> Adjacent<N> for HashMap<N, Vec<N>> {
+    fn adjacent<'a>(&'a self, node: &N) -> Box<dyn Iterator<Item = &'a N> + 'a> {
+        match self.get(node) {
+            Some(targets) => Box::new(targets.iter()),
+            None => Box::new(None.into_iter()),
+        }
+    }
+}
+
+impl<N, E, S> Adjacent<N> for Graph<N, E, S>
+where
+    N: Hash + Eq + Clone,
+    S: Storage<E>,
+{
+    fn adjacent<'a>(&'a self, node: &N) -> Box<dyn Iterator<Item = &'a N> + 'a> {
+        Box::new(self.neighbors(node).map(|(target, _)| target))
+    }
+}
+
+/// Anything a weighted search can walk: a `Graph`, or a map from each node
+/// to its neighbours and the weights of the edges to them.
+pub trait Weighted<N, E> {
+    /// The nodes `node` has an edge to, with the weights, none if it is
+    /// unknown.
+    fn weighted<'a>(&'a self, node: &N) -> Box<dyn Iterator<Item = (&'a N, &'a E)> + 'a>;
+}
+
+impl<N: Hash + Eq, E> Weighted<N, E> for HashMap<N, HashMap<N, E>> {
+    fn weighted<'a>(&'a self, node: &N) -> Box<dyn Iterator<Item = (&'a N, &'a E)> + 'a> {
+        match self.get(node) {
+            Some(targets) => Box::new(targets.iter()),
+            None => Box::new(None.into_iter()),
+        }
+    }
+}
+
+impl<N, E, S> Weighted<N, E> for Graph<N, E, S>
+where
+    N: Hash + Eq + Clone,
+    S: Storage<E>,
+{
+    fn weighted<'a>(&'a self, node: &N) -> Box<dyn Iterator<Item = (&'a N, &'a E)> + 'a> {
+        Box::new(self.neighbors(node))
+    }
+}
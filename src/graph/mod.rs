@@ -0,0 +1,2 @@
+pub mod adjacency;
+pub mod storage;
\ No newline at end of file
@@ -0,0 +1,175 @@
+use ndarray::Array2;
+
+/// How a `Graph` keeps its edges, between nodes known by their index.
+///
+/// There is at most one edge from a node to another; undirected graphs
+/// store each edge in both directions.
+pub trait Storage<E> {
+    fn new() -> Self;
+
+    fn node_count(&self) -> usize;
+
+    /// Add a node without edges, returning its index.
+    fn add_node(&mut self) -> usize;
+
+    /// Remove the node `index`, which must not have any edges left. The
+    /// last node takes its index.
+    fn remove_node(&mut self, index: usize);
+
+    /// Set the weight of the edge `from -> to`, returning the old one.
+    fn set_edge(&mut self, from: usize, to: usize, weight: E) -> Option<E>;
+
+    fn remove_edge(&mut self, from: usize, to: usize) -> Option<E>;
+
+    fn edge(&self, from: usize, to: usize) -> Option<&E>;
+
+    /// The nodes `from` has an edge to, with the weights.
+    fn neighbors<'a>(&'a self, from: usize) -> Box<dyn Iterator<Item = (usize, &'a E)> + 'a>;
+}
+
+/// Edge lists per node: O(V + E) space, O(degree) edge lookups.
+#[derive(Clone, Debug)]
+pub struct AdjacencyList<E> {
+    edges: Vec<Vec<(usize, E)>>,
+}
+
+impl<E> Storage<E> for AdjacencyList<E> {
+    fn new() -> Self {
+        AdjacencyList { edges: Vec::new() }
+    }
+
+    fn node_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn add_node(&mut self) -> usize {
+        self.edges.push(Vec::new());
+        self.edges.len() - 1
+    }
+
+    fn remove_node(&mut self, index: usize) {
+        let last = self.edges.len() - 1;
+        self.edges.swap_remove(index);
+        if index == last {
+            return;
+        }
+        for targets in &mut self.edges {
+            for target in targets.iter_mut() {
+                if target.0 == last {
+                    target.0 = index;
+                }
+            }
+        }
+    }
+
+    fn set_edge(&mut self, from: usize, to: usize, weight: E) -> Option<E> {
+        let targets = &mut self.edges[from];
+        match targets.iter().position(|&(t, _)| t == to) {
+            Some(i) => Some(::std::mem::replace(&mut targets[i].1, weight)),
+            None => {
+                targets.push((to, weight));
+                None
+            }
+        }
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) -> Option<E> {
+        let targets = &mut self.edges[from];
+        targets
+            .iter()
+            .position(|&(t, _)| t == to)
+            .map(|i| targets.swap_remove(i).1)
+    }
+
+    fn edge(&self, from: usize, to: usize) -> Option<&E> {
+        self.edges[from]
+            .iter()
+            .find(|&&(t, _)| t == to)
+            .map(|(_, weight)| weight)
+    }
+
+    fn neighbors<'a>(&'a self, from: usize) -> Box<dyn Iterator<Item = (usize, &'a E)> + 'a> {
+        Box::new(self.edges[from].iter().map(|&(t, ref weight)| (t, weight)))
+    }
+}
+
+/// `V x V` matrix of optional weights: O(V^2) space, O(1) edge lookups,
+/// O(V) to list the neighbours of a node.
+///
+/// The matrix grows by doubling, so adding a node is amortized O(V).
+#[derive(Clone, Debug)]
+pub struct AdjacencyMatrix<E> {
+    matrix: Array2<Option<E>>,
+    len: usize,
+}
+
+impl<E> AdjacencyMatrix<E> {
+    /// The matrix, `[[from, to]]`; only the first `node_count()` rows and
+    /// columns are used.
+    pub fn matrix(&self) -> &Array2<Option<E>> {
+        &self.matrix
+    }
+}
+
+impl<E> Storage<E> for AdjacencyMatrix<E> {
+    fn new() -> Self {
+        AdjacencyMatrix {
+            matrix: Array2::default((0, 0)),
+            len: 0,
+        }
+    }
+
+    fn node_count(&self) -> usize {
+        self.len
+    }
+
+    fn add_node(&mut self) -> usize {
+        let capacity = self.matrix.rows();
+        if self.len == capacity {
+            let capacity = if capacity == 0 { 4 } else { 2 * capacity };
+            let mut old = ::std::mem::replace(&mut self.matrix, Array2::default((0, 0)));
+            let len = self.len;
+            self.matrix = Array2::from_shape_fn((capacity, capacity), |(i, j)| {
+                if i < len && j < len {
+                    old[[i, j]].take()
+                } else {
+                    None
+                }
+            });
+        }
+        self.len += 1;
+        self.len - 1
+    }
+
+    fn remove_node(&mut self, index: usize) {
+        let last = self.len - 1;
+        if index != last {
+            for i in 0..last {
+                let weight = self.matrix[[last, i]].take();
+                self.matrix[[index, i]] = weight;
+                let weight = self.matrix[[i, last]].take();
+                self.matrix[[i, index]] = weight;
+            }
+            let weight = self.matrix[[last, last]].take();
+            self.matrix[[index, index]] = weight;
+        }
+        self.len -= 1;
+    }
+
+    fn set_edge(&mut self, from: usize, to: usize, weight: E) -> Option<E> {
+        self.matrix[[from, to]].replace(weight)
+    }
+
+    fn remove_edge(&mut self, from: usize, to: usize) -> Option<E> {
+        self.matrix[[from, to]].take()
+    }
+
+    fn edge(&self, from: usize, to: usize) -> Option<&E> {
+        self.matrix[[from, to]].as_ref()
+    }
+
+    fn neighbors<'a>(&'a self, from: usize) -> Box<dyn Iterator<Item = (usize, &'a E)> + 'a> {
+        let matrix = &self.matrix;
+        Box::new((0..self.len).filter_map(move |to| matrix[[from, to]].as_ref().map(|w| (to, w))))
+    }
+}
@@ -0,0 +1,155 @@
+use std::cmp::Ordering;
+
+/// d-ary heap ordered by a comparator.
+///
+/// The element that compares `Less` than every other is popped first, so
+/// `BinaryHeap::new()` is a min-heap; pass a reversed comparator for a
+/// max-heap.
+///
+/// # Example
+/// ```
+/// use algorithm::heap::binary_heap::BinaryHeap;
+///
+/// let mut heap = BinaryHeap::with_comparator(4, |a: &i32, b: &i32| b.cmp(a));
+/// heap.push(3);
+/// heap.push(7);
+/// heap.push(5);
+/// assert_eq!(heap.pop(), Some(7));
+/// ```
+pub struct BinaryHeap<T, F = fn(&T, &T) -> Ordering> {
+    data: Vec<T>,
+    arity: usize,
+    compare: F,
+}
+
+impl<T: Ord> Default for BinaryHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> BinaryHeap<T> {
+    pub fn new() -> Self {
+        BinaryHeap::with_arity(2)
+    }
+
+    pub fn with_arity(arity: usize) -> Self {
+        BinaryHeap::with_comparator(arity, Ord::cmp)
+    }
+}
+
+impl<T, F> BinaryHeap<T, F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    pub fn with_comparator(arity: usize, compare: F) -> Self {
+        assert!(arity >= 2, "arity of a heap must be at least 2");
+        BinaryHeap {
+            data: Vec::new(),
+            arity,
+            compare,
+        }
+    }
+
+    /// Build a heap from `data` in O(n).
+    pub fn from_vec(arity: usize, data: Vec<T>, compare: F) -> Self {
+        let mut heap = BinaryHeap::with_comparator(arity, compare);
+        heap.data = data;
+        if heap.data.len() > 1 {
+            let last_parent = (heap.data.len() - 2) / heap.arity;
+            for i in (0..last_parent + 1).rev() {
+                heap.sift_down(i);
+            }
+        }
+        heap
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+        let last = self.data.len() - 1;
+        self.sift_up(last);
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let top = self.data.swap_remove(0);
+        if !self.data.is_empty() {
+            self.sift_down(0);
+        }
+        Some(top)
+    }
+
+    /// Consume the heap, returning its elements in popping order.
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.len());
+        while let Some(v) = self.pop() {
+            sorted.push(v);
+        }
+        sorted
+    }
+
+    // ---------------------------------------------------------------------------------
+    //                              helper methods
+    // ---------------------------------------------------------------------------------
+    fn less(&self, i: usize, j: usize) -> bool {
+        (self.compare)(&self.data[i], &self.data[j]) == Ordering::Less
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / self.arity;
+            if !self.less(i, parent) {
+                break;
+            }
+            self.data.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let first = i * self.arity + 1;
+            if first >= self.data.len() {
+                break;
+            }
+
+            // find the smallest child
+            let last = (first + self.arity).min(self.data.len());
+            let mut child = first;
+            for c in first + 1..last {
+                if self.less(c, child) {
+                    child = c;
+                }
+            }
+
+            if !self.less(child, i) {
+                break;
+            }
+            self.data.swap(i, child);
+            i = child;
+        }
+    }
+}
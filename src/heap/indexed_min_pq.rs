@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Min priority queue whose entries can be looked up by key, so the
+/// priority of a queued key can be changed or the key removed in O(log n).
+///
+/// # Example
+/// ```
+/// use algorithm::heap::indexed_min_pq::IndexedMinPQ;
+///
+/// let mut pq = IndexedMinPQ::new();
+/// pq.push("a", 6);
+/// pq.push("b", 2);
+/// pq.push("fin", 100);
+/// pq.decrease_key(&"fin", 5);
+/// assert_eq!(pq.pop(), Some(("b", 2)));
+/// assert_eq!(pq.pop(), Some(("fin", 5)));
+/// ```
+pub struct IndexedMinPQ<K, P> {
+    heap: Vec<(K, P)>,
+    // key => index in `heap`
+    positions: HashMap<K, usize>,
+}
+
+impl<K: Hash + Eq + Clone, P: PartialOrd> Default for IndexedMinPQ<K, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: PartialOrd> IndexedMinPQ<K, P> {
+    pub fn new() -> Self {
+        IndexedMinPQ {
+            heap: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.positions.get(key).map(|&i| &self.heap[i].1)
+    }
+
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|(k, p)| (k, p))
+    }
+
+    /// Queue `key`, or change its priority if it is already queued.
+    /// Returns the previous priority of the key.
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        if self.contains(&key) {
+            return self.change_priority(&key, priority);
+        }
+
+        let i = self.heap.len();
+        self.positions.insert(key.clone(), i);
+        self.heap.push((key, priority));
+        self.sift_up(i);
+        None
+    }
+
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        Some(self.remove_at(0))
+    }
+
+    /// Lower the priority of a queued key, returning `false` (and leaving the
+    /// queue untouched) if the key is missing or `priority` is not lower.
+    pub fn decrease_key(&mut self, key: &K, priority: P) -> bool {
+        let i = match self.positions.get(key) {
+            Some(&i) => i,
+            None => return false,
+        };
+        if priority.partial_cmp(&self.heap[i].1) != Some(Ordering::Less) {
+            return false;
+        }
+
+        self.heap[i].1 = priority;
+        self.sift_up(i);
+        true
+    }
+
+    /// Set the priority of a queued key, returning the old one.
+    pub fn change_priority(&mut self, key: &K, priority: P) -> Option<P> {
+        let i = *self.positions.get(key)?;
+
+        let old = ::std::mem::replace(&mut self.heap[i].1, priority);
+        self.sift_up(i);
+        let i = self.positions[key];
+        self.sift_down(i);
+        Some(old)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<P> {
+        let i = *self.positions.get(key)?;
+        Some(self.remove_at(i).1)
+    }
+
+    // ---------------------------------------------------------------------------------
+    //                              helper methods
+    // ---------------------------------------------------------------------------------
+    fn remove_at(&mut self, i: usize) -> (K, P) {
+        let last = self.heap.len() - 1;
+        self.swap(i, last);
+        let (key, priority) = self.heap.pop().unwrap();
+        self.positions.remove(&key);
+
+        if i < self.heap.len() {
+            self.sift_up(i);
+            let i = self.positions[&self.heap[i].0];
+            self.sift_down(i);
+        }
+        (key, priority)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        *self.positions.get_mut(&self.heap[i].0).unwrap() = i;
+        *self.positions.get_mut(&self.heap[j].0).unwrap() = j;
+    }
+
+    fn less(&self, i: usize, j: usize) -> bool {
+        self.heap[i].1 < self.heap[j].1
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.less(i, parent) {
+                break;
+            }
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let left = 2 * i + 1;
+            if left >= self.heap.len() {
+                break;
+            }
+
+            let right = left + 1;
+            let child = if right < self.heap.len() && self.less(right, left) {
+                right
+            } else {
+                left
+            };
+
+            if !self.less(child, i) {
+                break;
+            }
+            self.swap(i, child);
+            i = child;
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod binary_heap;
+pub mod indexed_min_pq;
+pub mod pairing_heap;
\ No newline at end of file
@@ -0,0 +1,130 @@
+use std::mem;
+
+struct Node<T> {
+    value: T,
+    children: Vec<Box<Node<T>>>,
+}
+
+/// Min pairing heap: `push`, `peek` and `meld` are O(1), `pop` is
+/// O(log n) amortized.
+///
+/// # Example
+/// ```
+/// use algorithm::heap::pairing_heap::PairingHeap;
+///
+/// let mut a = PairingHeap::new();
+/// a.push(5);
+/// a.push(1);
+/// let mut b = PairingHeap::new();
+/// b.push(3);
+/// a.meld(b);
+/// assert_eq!(a.pop(), Some(1));
+/// assert_eq!(a.pop(), Some(3));
+/// ```
+pub struct PairingHeap<T: PartialOrd> {
+    root: Option<Box<Node<T>>>,
+    len: usize,
+}
+
+impl<T: PartialOrd> Default for PairingHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialOrd> PairingHeap<T> {
+    pub fn new() -> Self {
+        PairingHeap { root: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.root.as_ref().map(|n| &n.value)
+    }
+
+    pub fn push(&mut self, value: T) {
+        let node = Box::new(Node {
+            value,
+            children: Vec::new(),
+        });
+        let root = self.root.take();
+        self.root = Some(Self::link(root, node));
+        self.len += 1;
+    }
+
+    /// Move every element of `other` into this heap.
+    pub fn meld(&mut self, mut other: PairingHeap<T>) {
+        if let Some(node) = other.root.take() {
+            let root = self.root.take();
+            self.root = Some(Self::link(root, node));
+            self.len += other.len;
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let root = self.root.take()?;
+        let Node { value, children } = *root;
+        self.root = Self::merge_pairs(children);
+        self.len -= 1;
+        Some(value)
+    }
+
+    // ---------------------------------------------------------------------------------
+    //                              helper methods
+    // ---------------------------------------------------------------------------------
+    fn link(root: Option<Box<Node<T>>>, mut node: Box<Node<T>>) -> Box<Node<T>> {
+        match root {
+            None => node,
+            Some(mut root) => {
+                if node.value < root.value {
+                    node.children.push(root);
+                    node
+                } else {
+                    root.children.push(node);
+                    root
+                }
+            }
+        }
+    }
+
+    /// Two-pass pairing: meld the children pairwise from left to right,
+    /// then meld the results from right to left.
+    fn merge_pairs(children: Vec<Box<Node<T>>>) -> Option<Box<Node<T>>> {
+        let mut pairs = Vec::with_capacity(children.len() / 2 + 1);
+        let mut iter = children.into_iter();
+        loop {
+            match (iter.next(), iter.next()) {
+                (Some(a), Some(b)) => pairs.push(Self::link(Some(a), b)),
+                (Some(a), None) => pairs.push(a),
+                _ => break,
+            }
+        }
+
+        let mut root = None;
+        while let Some(node) = pairs.pop() {
+            root = Some(Self::link(root, node));
+        }
+        root
+    }
+}
+
+impl<T: PartialOrd> Drop for PairingHeap<T> {
+    fn drop(&mut self) {
+        // drop iteratively, a degenerated heap can be as deep as it is long
+        let mut stack = Vec::new();
+        if let Some(root) = self.root.take() {
+            stack.push(root);
+        }
+        while let Some(mut node) = stack.pop() {
+            let children = mem::take(&mut node.children);
+            stack.extend(children);
+        }
+    }
+}
@@ -4,6 +4,9 @@
 extern crate ndarray;
 extern crate quickersort;
 extern crate rand;
+extern crate rayon;
+extern crate serde;
+extern crate serde_json;
 
 
 #[macro_use]
@@ -13,4 +16,6 @@ pub mod utils;
 pub mod search;
 pub mod sort;
 pub mod dynamic;
-pub mod tree;
\ No newline at end of file
+pub mod tree;
+pub mod heap;
+pub mod graph;
\ No newline at end of file
@@ -1,20 +1,339 @@
-use std::cmp::{ PartialOrd, PartialEq };
+use std::cmp::{Ordering, PartialEq, PartialOrd};
+use std::ops::Range;
 
-pub fn binary_search<T: PartialOrd + PartialEq>(source: &Vec<T>, target: &T) -> bool {
-    let mut lo:usize = 0;
-    let mut hi:usize = (*source).len() - 1;
+/// Whether the sorted slice contains `target`.
+///
+/// # Example
+/// ```
+/// use algorithm::search::binary::binary_search;
+///
+/// assert!(binary_search(&[1, 3, 5], &3));
+/// assert!(!binary_search(&[1, 3, 5], &0));
+/// assert!(!binary_search(&[], &0));
+/// ```
+pub fn binary_search<T: PartialOrd + PartialEq>(source: &[T], target: &T) -> bool {
+    let i = lower_bound(source, target);
+    i < source.len() && source[i] == *target
+}
 
-    while lo <= hi {
-        println!("[{}, {}]", lo, hi);
+/// Index of the first element for which `pred` is false, in a slice
+/// where `pred` is true for a prefix and false for the rest.
+///
+/// # Example
+/// ```
+/// use algorithm::search::binary::partition_point;
+///
+/// assert_eq!(partition_point(&[1, 2, 3, 10, 20], |&x| x < 5), 3);
+/// ```
+pub fn partition_point<T, P>(source: &[T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    // the answer is in [lo, hi]
+    let mut lo = 0;
+    let mut hi = source.len();
+    while lo < hi {
         let mid = lo + (hi - lo) / 2;
-        if *target < (*source)[mid] {
-            hi = mid - 1;
-        } else if *target > (*source)[mid] {
+        if pred(&source[mid]) {
             lo = mid + 1;
         } else {
-            return true
+            hi = mid;
         }
     }
+    lo
+}
+
+/// Index of the first element not less than `target`: where it would be
+/// inserted before any equal elements.
+pub fn lower_bound<T: PartialOrd>(source: &[T], target: &T) -> usize {
+    partition_point(source, |x| x < target)
+}
+
+/// Index of the first element greater than `target`: where it would be
+/// inserted after any equal elements.
+pub fn upper_bound<T: PartialOrd>(source: &[T], target: &T) -> usize {
+    partition_point(source, |x| target.partial_cmp(x) != Some(Ordering::Less))
+}
+
+/// Range of the elements equal to `target`, empty (at its insertion
+/// point) if there are none.
+///
+/// # Example
+/// ```
+/// use algorithm::search::binary::equal_range;
+///
+/// let source = [1, 2, 2, 2, 3];
+/// assert_eq!(equal_range(&source, &2), 1..4);
+/// assert_eq!(equal_range(&source, &0), 0..0);
+/// ```
+pub fn equal_range<T: PartialOrd>(source: &[T], target: &T) -> Range<usize> {
+    let lo = lower_bound(source, target);
+    let hi = lo + upper_bound(&source[lo..], target);
+    lo..hi
+}
+
+/// Search a slice sorted by `compare`, which tells how an element orders
+/// relative to the one looked for.
+///
+/// Returns `Ok` with the index of the first match, or `Err` with the index
+/// where a match would be inserted.
+///
+/// # Example
+/// ```
+/// use algorithm::search::binary::binary_search_by;
+///
+/// let source = [1, 3, 3, 5];
+/// assert_eq!(binary_search_by(&source, |x| x.cmp(&3)), Ok(1));
+/// assert_eq!(binary_search_by(&source, |x| x.cmp(&4)), Err(3));
+/// ```
+pub fn binary_search_by<T, F>(source: &[T], mut compare: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let i = partition_point(source, |x| compare(x) == Ordering::Less);
+    if i < source.len() && compare(&source[i]) == Ordering::Equal {
+        Ok(i)
+    } else {
+        Err(i)
+    }
+}
+
+/// Like `binary_search_by`, for a slice sorted by the key `key` extracts.
+pub fn binary_search_by_key<T, K, F>(source: &[T], target: &K, mut key: F) -> Result<usize, usize>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    binary_search_by(source, |x| key(x).cmp(target))
+}
 
-    false
+/// Integers `find_smallest` and `find_largest` can search.
+pub trait Midpoint: Copy + PartialOrd {
+    /// `lo + (hi - lo) / 2`, without overflow, for `lo < hi`.
+    fn midpoint(lo: Self, hi: Self) -> Self;
+    fn successor(self) -> Self;
+    fn predecessor(self) -> Self;
+}
+
+macro_rules! impl_midpoint {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl Midpoint for $t {
+                fn midpoint(lo: $t, hi: $t) -> $t {
+                    // the distance always fits the unsigned type
+                    let half = (hi as $u).wrapping_sub(lo as $u) / 2;
+                    lo.wrapping_add(half as $t)
+                }
+
+                fn successor(self) -> $t {
+                    self + 1
+                }
+
+                fn predecessor(self) -> $t {
+                    self - 1
+                }
+            }
+        )*
+    };
+}
+
+impl_midpoint!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize
+);
+
+/// Smallest `x` of `range` for which `pred` holds, where `pred` is false
+/// up to some point and true from there on. `None` if it never holds.
+///
+/// # Example
+/// ```
+/// use algorithm::search::binary::find_smallest;
+///
+/// // smallest x with x * x >= 1000
+/// assert_eq!(find_smallest(0..1000, |x| x * x >= 1000), Some(32));
+/// assert_eq!(find_smallest(0..10, |x| x > 20), None);
+/// ```
+pub fn find_smallest<T, P>(range: Range<T>, mut pred: P) -> Option<T>
+where
+    T: Midpoint,
+    P: FnMut(T) -> bool,
+{
+    let mut lo = range.start;
+    let mut hi = range.end;
+    while lo < hi {
+        let mid = T::midpoint(lo, hi);
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid.successor();
+        }
+    }
+    if lo < range.end {
+        Some(lo)
+    } else {
+        None
+    }
+}
+
+/// Largest `x` of `range` for which `pred` holds, where `pred` is true
+/// up to some point and false from there on. `None` if it never holds.
+///
+/// # Example
+/// ```
+/// use algorithm::search::binary::find_largest;
+///
+/// // largest x with x * x <= 1000
+/// assert_eq!(find_largest(0..1000, |x| x * x <= 1000), Some(31));
+/// ```
+pub fn find_largest<T, P>(range: Range<T>, mut pred: P) -> Option<T>
+where
+    T: Midpoint,
+    P: FnMut(T) -> bool,
+{
+    let start = range.start;
+    let end = range.end;
+    match find_smallest(range, |x| !pred(x)) {
+        Some(x) if x > start => Some(x.predecessor()),
+        Some(_) => None,
+        None if start < end => Some(end.predecessor()),
+        None => None,
+    }
+}
+
+/// Bisect `range` down to `tolerance` (or `max_iterations` halvings) for
+/// the point where `pred` changes from false to true, returning the end
+/// of the last bracket, where `pred` holds if it held at `range.end`.
+///
+/// # Example
+/// ```
+/// use algorithm::search::binary::bisect;
+///
+/// let sqrt2 = bisect(0.0..2.0, 1e-12, 100, |x| x * x >= 2.0);
+/// assert!((sqrt2 - 2f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn bisect<P>(range: Range<f64>, tolerance: f64, max_iterations: usize, mut pred: P) -> f64
+where
+    P: FnMut(f64) -> bool,
+{
+    let mut lo = range.start;
+    let mut hi = range.end;
+    for _ in 0..max_iterations {
+        if hi - lo <= tolerance {
+            break;
+        }
+        let mid = lo + (hi - lo) / 2.0;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+/// Root of `f` in `range` by bisection, `None` if `f` has the same sign
+/// at both ends.
+///
+/// # Example
+/// ```
+/// use algorithm::search::binary::bisect_root;
+///
+/// let root = bisect_root(0.0..2.0, 1e-9, 100, |x| x.cos() - x).unwrap();
+/// assert!((root.cos() - root).abs() < 1e-8);
+/// assert_eq!(bisect_root(0.0..1.0, 1e-9, 100, |x| x + 1.0), None);
+/// ```
+pub fn bisect_root<F>(
+    range: Range<f64>,
+    tolerance: f64,
+    max_iterations: usize,
+    mut f: F,
+) -> Option<f64>
+where
+    F: FnMut(f64) -> f64,
+{
+    let start = f(range.start);
+    let end = f(range.end);
+    if start == 0.0 {
+        return Some(range.start);
+    }
+    if start.signum() == end.signum() && end != 0.0 {
+        return None;
+    }
+    let sign = start.signum();
+    Some(bisect(range, tolerance, max_iterations, |x| {
+        f(x).signum() != sign
+    }))
+}
+
+/// Minimum of a function which decreases then increases on `range`, by
+/// ternary search: each step compares `f` at the thirds and drops the
+/// third which cannot hold the minimum. Negate `f` for a maximum.
+///
+/// # Example
+/// ```
+/// use algorithm::search::binary::ternary_search;
+///
+/// let x = ternary_search(-10.0..10.0, 1e-9, 200, |x| (x - 3.0) * (x - 3.0));
+/// assert!((x - 3.0).abs() < 1e-6);
+/// ```
+pub fn ternary_search<F>(range: Range<f64>, tolerance: f64, max_iterations: usize, mut f: F) -> f64
+where
+    F: FnMut(f64) -> f64,
+{
+    let mut lo = range.start;
+    let mut hi = range.end;
+    for _ in 0..max_iterations {
+        if hi - lo <= tolerance {
+            break;
+        }
+        let m1 = lo + (hi - lo) / 3.0;
+        let m2 = hi - (hi - lo) / 3.0;
+        if f(m1) < f(m2) {
+            hi = m2;
+        } else {
+            lo = m1;
+        }
+    }
+    lo + (hi - lo) / 2.0
+}
+
+/// Like `ternary_search`, by golden-section search: the interval shrinks
+/// by the golden ratio each step and one of the two points is reused, so
+/// each step costs one evaluation of `f` instead of two.
+pub fn golden_section_search<F>(
+    range: Range<f64>,
+    tolerance: f64,
+    max_iterations: usize,
+    mut f: F,
+) -> f64
+where
+    F: FnMut(f64) -> f64,
+{
+    // 1 / phi
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let mut lo = range.start;
+    let mut hi = range.end;
+    let mut x1 = hi - ratio * (hi - lo);
+    let mut x2 = lo + ratio * (hi - lo);
+    let mut f1 = f(x1);
+    let mut f2 = f(x2);
+    for _ in 0..max_iterations {
+        if hi - lo <= tolerance {
+            break;
+        }
+        if f1 < f2 {
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - ratio * (hi - lo);
+            f1 = f(x1);
+        } else {
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + ratio * (hi - lo);
+            f2 = f(x2);
+        }
+    }
+    lo + (hi - lo) / 2.0
 }
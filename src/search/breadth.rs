@@ -1,112 +1,151 @@
-use std::collections::HashMap;
-use std::fmt::{Debug, Display};
-use std::clone::Clone;
-use std::cmp::Eq;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::Hash;
 
-pub fn breadth_search_generic<T: Display + Debug + Clone + Eq + Hash, F>(
-    source: &HashMap<T, Vec<T>>,
-    starter: &T,
-    condition: F,
-) -> bool
+use graph::adjacency::Adjacent;
+
+/// What `breadth_search` found.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BreadthSearch<T: Hash + Eq> {
+    /// The node closest to the starter which meets the condition.
+    pub found: Option<T>,
+    /// A shortest path from the starter to `found`, both included; empty
+    /// if nothing was found.
+    pub path: Vec<T>,
+    /// Number of edges from the starter to every node reached before the
+    /// search stopped.
+    pub distances: HashMap<T, usize>,
+}
+
+/// Breadth-first search from `starter` (included) for a node meeting
+/// `condition`, over a `Graph` or a `HashMap<T, Vec<T>>` of the nodes each
+/// node has an edge to.
+///
+/// Nodes are visited in order of their distance from `starter`, so the one
+/// found is a closest one. With a condition which never holds, this walks
+/// everything reachable and `distances` has them all.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use algorithm::search::breadth::breadth_search;
+///
+/// let mut graph = HashMap::new();
+/// graph.insert("you", vec!["alice", "bob"]);
+/// graph.insert("alice", vec!["peggy"]);
+/// graph.insert("bob", vec!["anuj", "peggy"]);
+/// graph.insert("peggy", vec!["thom"]);
+///
+/// let result = breadth_search(&graph, &"you", |name| name.ends_with('m'));
+/// assert_eq!(result.found, Some("thom"));
+/// assert_eq!(result.path.len(), 4);
+/// assert_eq!(result.distances["peggy"], 2);
+/// ```
+pub fn breadth_search<T, G, F>(graph: &G, starter: &T, mut condition: F) -> BreadthSearch<T>
 where
-    F: Fn(&T) -> bool,
+    T: Hash + Eq + Clone,
+    G: Adjacent<T>,
+    F: FnMut(&T) -> bool,
 {
-    let mut search_queue = Vec::new();
-    search_queue.extend_from_slice(source.get(starter).unwrap());
-    let mut searched: Vec<T> = Vec::new();
+    let mut distances = HashMap::new();
+    let mut parents: HashMap<T, T> = HashMap::new();
+    let mut queue = VecDeque::new();
+    // `distances` doubles as the set of nodes already queued
+    distances.insert(starter.clone(), 0);
+    queue.push_back(starter.clone());
 
-    loop {
-        if search_queue.len() <= 0 as usize {
-            return false;
+    while let Some(node) = queue.pop_front() {
+        if condition(&node) {
+            let path = path_to(&parents, &node);
+            return BreadthSearch {
+                found: Some(node),
+                path,
+                distances,
+            };
         }
 
-        let source_str = format!("{:?}", search_queue);
-        let person = search_queue.pop().unwrap();
-        println!("[{:?}] from {:?}", person, source_str);
-
-        if searched.contains(&person) {
-            continue;
+        let distance = distances[&node] + 1;
+        for next in graph.adjacent(&node) {
+            if !distances.contains_key(next) {
+                distances.insert(next.clone(), distance);
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next.clone());
+            }
         }
+    }
 
-        if condition(&person) {
-            println!("{} is a mongo seller!", person);
-            return true;
-        }
-        if let Some(friends) = source.get(&person) {
-            search_queue.extend_from_slice(friends);
-        }
-        searched.push(person);
+    BreadthSearch {
+        found: None,
+        path: Vec::new(),
+        distances,
     }
 }
 
-pub fn breadth_search<F>(source: &HashMap<&str, Vec<&str>>, starter: &str, condition: F) -> bool
-where
-    F: Fn(&str) -> bool,
-{
-    let mut search_queue = Vec::new();
-    search_queue.extend_from_slice(&source[starter]);
-    let mut searched = Vec::new();
-
-    loop {
-        if search_queue.len() <= 0 as usize {
-            return false;
-        }
-
-        let source_str = format!("{:?}", search_queue);
-        let person = search_queue.pop().unwrap();
-        println!("[{}] from {}", person, source_str);
+/// Path from the root of the search tree to `node`, following `parents`.
+fn path_to<T: Hash + Eq + Clone>(parents: &HashMap<T, T>, node: &T) -> Vec<T> {
+    let mut path = vec![node.clone()];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
 
-        if searched.contains(&person) {
-            continue;
-        }
+/// Iterator over the nodes reachable from a start node, breadth first, see
+/// `bfs_iter`.
+pub struct BreadthFirst<'a, T, G: 'a> {
+    graph: &'a G,
+    queue: VecDeque<T>,
+    // every node queued so far
+    seen: HashSet<T>,
+}
 
-        if condition(person) {
-            println!("{} is a mongo seller!", person);
-            return true;
-        }
-        if let Some(friends) = source.get(person) {
-            search_queue.extend_from_slice(friends);
-        }
-        searched.push(person);
+/// Nodes reachable from `start` (included), in order of their distance
+/// from it, over a `Graph` or a `HashMap<T, Vec<T>>` of the nodes each
+/// node has an edge to.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use algorithm::search::breadth::bfs_iter;
+///
+/// let mut graph = HashMap::new();
+/// graph.insert("a", vec!["b", "d"]);
+/// graph.insert("b", vec!["c"]);
+/// graph.insert("d", vec!["c"]);
+///
+/// let order: Vec<&str> = bfs_iter(&graph, "a").collect();
+/// assert_eq!(order, vec!["a", "b", "d", "c"]);
+/// ```
+pub fn bfs_iter<'a, T, G>(graph: &'a G, start: T) -> BreadthFirst<'a, T, G>
+where
+    T: Hash + Eq + Clone,
+    G: Adjacent<T>,
+{
+    let mut seen = HashSet::new();
+    seen.insert(start.clone());
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+    BreadthFirst {
+        graph,
+        queue,
+        seen,
     }
 }
 
-pub fn breadth_search_new<F>(
-    source: &HashMap<String, Vec<String>>,
-    starter: &str,
-    condition: F,
-) -> bool
+impl<'a, T, G> Iterator for BreadthFirst<'a, T, G>
 where
-    F: Fn(&str) -> bool,
+    T: Hash + Eq + Clone,
+    G: Adjacent<T>,
 {
-    let mut search_queue = Vec::new();
-    search_queue.extend_from_slice(&source[starter]);
-    let mut searched: Vec<String> = Vec::new();
+    type Item = T;
 
-    loop {
-        if search_queue.len() <= 0 as usize {
-            return false;
-        }
-
-        let source_str = format!("{:?}", search_queue);
-        let person = search_queue.pop().unwrap();
-        println!("[{}] from {}", person, source_str);
-
-        if searched.contains(&person) {
-            continue;
-        }
-
-        // person: String
-        // *person: str (via Deref<Target=str>, *deref => *&str => str)
-        // &*person: &str
-        if condition(&*person) {
-            println!("{} is a mongo seller!", person);
-            return true;
-        }
-        if let Some(friends) = source.get(&*person) {
-            search_queue.extend_from_slice(friends);
+    fn next(&mut self) -> Option<T> {
+        let node = self.queue.pop_front()?;
+        for next in self.graph.adjacent(&node) {
+            if self.seen.insert(next.clone()) {
+                self.queue.push_back(next.clone());
+            }
         }
-        searched.push(person);
+        Some(node)
     }
 }
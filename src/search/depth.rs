@@ -0,0 +1,190 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use graph::adjacency::Adjacent;
+
+/// Kind of an edge `from -> to` met by `depth_first_search`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EdgeKind {
+    /// `to` was discovered through this edge.
+    Tree,
+    /// `to` is an ancestor of `from` (or `from` itself): the edge closes a
+    /// cycle.
+    Back,
+    /// `to` is a descendant of `from` found through another path.
+    Forward,
+    /// Any other edge, to a node already finished.
+    Cross,
+}
+
+/// Events of a `depth_first_search`; every method does nothing by default.
+///
+/// Times count the discover and finish events so far, starting from 1, so
+/// a node `v` is a descendant of `u` exactly when
+/// `discover(u) < discover(v) < finish(v) < finish(u)`.
+pub trait Visitor<T> {
+    fn discover(&mut self, _node: &T, _time: usize) {}
+
+    /// `node` and everything reachable from it has been walked.
+    fn finish(&mut self, _node: &T, _time: usize) {}
+
+    fn edge(&mut self, _from: &T, _to: &T, _kind: EdgeKind) {}
+}
+
+/// Depth-first search from each of `roots` not reached yet, telling
+/// `visitor` about every node and edge. Passing all the nodes as roots
+/// walks the whole graph.
+///
+/// Iterative, so deep graphs do not overflow the stack. In an undirected
+/// `Graph` every edge is met from both ends, so the edge back to the parent
+/// shows up as a `Back` edge.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use algorithm::search::depth::{depth_first_search, EdgeKind, Visitor};
+///
+/// struct Cycles(usize);
+///
+/// impl Visitor<u32> for Cycles {
+///     fn edge(&mut self, _from: &u32, _to: &u32, kind: EdgeKind) {
+///         if kind == EdgeKind::Back {
+///             self.0 += 1;
+///         }
+///     }
+/// }
+///
+/// let mut graph = HashMap::new();
+/// graph.insert(1, vec![2]);
+/// graph.insert(2, vec![3]);
+/// graph.insert(3, vec![1]);
+///
+/// let mut cycles = Cycles(0);
+/// depth_first_search(&graph, vec![1], &mut cycles);
+/// assert_eq!(cycles.0, 1);
+/// ```
+pub fn depth_first_search<T, G, I, V>(graph: &G, roots: I, visitor: &mut V)
+where
+    T: Hash + Eq + Clone,
+    G: Adjacent<T>,
+    I: IntoIterator<Item = T>,
+    V: Visitor<T>,
+{
+    let mut time = 0;
+    let mut discovered: HashMap<T, usize> = HashMap::new();
+    let mut finished: HashSet<T> = HashSet::new();
+
+    for root in roots {
+        if discovered.contains_key(&root) {
+            continue;
+        }
+
+        time += 1;
+        visitor.discover(&root, time);
+        discovered.insert(root.clone(), time);
+        let mut stack = vec![(root.clone(), graph.adjacent(&root))];
+
+        while let Some(&mut (ref node, ref mut targets)) = stack.last_mut() {
+            let (node, next) = (node.clone(), targets.next());
+
+            match next {
+                Some(next) => match discovered.get(next) {
+                    None => {
+                        visitor.edge(&node, next, EdgeKind::Tree);
+                        time += 1;
+                        visitor.discover(next, time);
+                        discovered.insert(next.clone(), time);
+                        stack.push((next.clone(), graph.adjacent(next)));
+                    }
+                    Some(_) if !finished.contains(next) => {
+                        visitor.edge(&node, next, EdgeKind::Back);
+                    }
+                    Some(&time_next) => {
+                        let kind = if discovered[&node] < time_next {
+                            EdgeKind::Forward
+                        } else {
+                            EdgeKind::Cross
+                        };
+                        visitor.edge(&node, next, kind);
+                    }
+                },
+                None => {
+                    time += 1;
+                    visitor.finish(&node, time);
+                    finished.insert(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over the nodes reachable from a start node, depth first, see
+/// `dfs_iter`.
+pub struct DepthFirst<'a, T: 'a, G: 'a> {
+    graph: &'a G,
+    start: Option<T>,
+    stack: Vec<Box<dyn Iterator<Item = &'a T> + 'a>>,
+    seen: HashSet<T>,
+}
+
+/// Nodes reachable from `start` (included), in the preorder of a recursive
+/// depth-first search, over a `Graph` or a `HashMap<T, Vec<T>>` of the
+/// nodes each node has an edge to.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use algorithm::search::depth::dfs_iter;
+///
+/// let mut graph = HashMap::new();
+/// graph.insert("a", vec!["b", "d"]);
+/// graph.insert("b", vec!["c"]);
+/// graph.insert("d", vec!["c"]);
+///
+/// let order: Vec<&str> = dfs_iter(&graph, "a").collect();
+/// assert_eq!(order, vec!["a", "b", "c", "d"]);
+/// ```
+pub fn dfs_iter<'a, T, G>(graph: &'a G, start: T) -> DepthFirst<'a, T, G>
+where
+    T: Hash + Eq + Clone,
+    G: Adjacent<T>,
+{
+    DepthFirst {
+        graph,
+        start: Some(start),
+        stack: Vec::new(),
+        seen: HashSet::new(),
+    }
+}
+
+impl<'a, T, G> Iterator for DepthFirst<'a, T, G>
+where
+    T: Hash + Eq + Clone,
+    G: Adjacent<T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if let Some(start) = self.start.take() {
+            self.stack.push(self.graph.adjacent(&start));
+            self.seen.insert(start.clone());
+            return Some(start);
+        }
+
+        loop {
+            let next = self.stack.last_mut()?.next();
+            match next {
+                Some(node) => {
+                    if self.seen.insert(node.clone()) {
+                        self.stack.push(self.graph.adjacent(node));
+                        return Some(node.clone());
+                    }
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
@@ -7,7 +7,14 @@ use std::cmp::{Eq, PartialEq};
 use std::hash::Hash;
 use std::clone::Clone;
 use std::fmt::{Debug, Display};
+use std::ops::Add;
 
+use graph::adjacency::Weighted;
+use heap::indexed_min_pq::IndexedMinPQ;
+
+/// Cheapest path from `start` to `end`, given the `costs` and `parents` of
+/// the neighbours of `start`, empty if `end` is unreachable.
+#[deprecated(note = "use `dijkstra` or `shortest_path` instead")]
 pub fn search<K: PartialEq + Eq + Hash + Clone + Debug + Display>(
     graph: &HashMap<K, HashMap<K, f32>>,
     start: &K,
@@ -17,37 +24,36 @@ pub fn search<K: PartialEq + Eq + Hash + Clone + Debug + Display>(
 ) -> Vec<K> {
     let mut processed = Vec::new();
 
-    let mut node = find_lowest_cost_node(costs, &processed);
-    loop {
-        if node == None {
-            break;
-        }
-
-        let node_key = node.unwrap();
-        println!("lowest_cost_node:{:?}", node_key);
-        let cost = *costs.get(&node_key).unwrap();
-        let neighbors = graph.get(&node_key).unwrap();
-        for n in neighbors.keys() {
-            let new_cost = cost + neighbors[n];
-            if costs[n] > new_cost {
-                costs.insert((*n).clone(), new_cost);
-                parents.insert((*n).clone(), Some(node_key.clone()));
+    while let Some(node_key) = find_lowest_cost_node(costs, &processed) {
+        let cost = costs[&node_key];
+        if let Some(neighbors) = graph.get(&node_key) {
+            for (n, weight) in neighbors {
+                let new_cost = cost + weight;
+                let cheaper = match costs.get(n) {
+                    Some(&old) => old > new_cost,
+                    None => true,
+                };
+                if cheaper {
+                    costs.insert((*n).clone(), new_cost);
+                    parents.insert((*n).clone(), Some(node_key.clone()));
+                }
             }
         }
-        processed.extend_from_slice(&vec![node_key.clone()]);
-        node = find_lowest_cost_node(costs, &processed);
+        processed.push(node_key);
     }
 
     // generate the path
     get_path(parents, start, end)
 }
 
-fn find_lowest_cost_node<T: Eq + Hash + Clone>(costs: &mut HashMap<T, f32>, processed: &Vec<T>) -> Option<T> {
+fn find_lowest_cost_node<T: Eq + Hash + Clone>(
+    costs: &HashMap<T, f32>,
+    processed: &[T],
+) -> Option<T> {
     let mut lowest_cost = f32::INFINITY;
     let mut lowest_cost_node = None;
 
-    for node in costs.keys() {
-        let cost = *costs.get(node).unwrap();
+    for (node, &cost) in costs {
         if cost < lowest_cost && !processed.contains(node) {
             lowest_cost = cost;
             lowest_cost_node = Some((*node).clone());
@@ -62,20 +68,152 @@ fn get_path<K: PartialEq + Eq + Hash + Clone>(
     start: &K,
     end: &K,
 ) -> Vec<K> {
-    let mut path = Vec::new();
-    // let x = parents.get(end).unwrap();
-    // let y = (*x).clone().unwrap();
-    path.extend_from_slice(&vec![(*end).clone()]);
-    path.extend_from_slice(&vec![(*parents.get(end).unwrap()).clone().unwrap()]);
-    loop {
-        let parent = path.last().unwrap().clone();
-        if parent == *start {
-            break;
+    let mut path = vec![(*end).clone()];
+    let mut node = end;
+    while node != start {
+        match parents.get(node) {
+            // a cycle of parents would never reach `start`
+            Some(Some(parent)) if !path.contains(parent) => {
+                path.push(parent.clone());
+                node = parent;
+            }
+            _ => return Vec::new(),
         }
-
-        path.extend_from_slice(&vec![(*parents.get(&parent).unwrap()).clone().unwrap()]);
     }
 
     path.reverse();
     path
 }
+
+/// Cheapest paths from one start node, computed by `dijkstra`.
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<N: Hash + Eq, W> {
+    start: N,
+    // final costs of the settled nodes
+    costs: HashMap<N, W>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Hash + Eq + Clone, W: Clone> ShortestPaths<N, W> {
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// Cost of the cheapest path to `target`, `None` if it is unreachable
+    /// (or was not reached before the search stopped).
+    pub fn cost(&self, target: &N) -> Option<&W> {
+        self.costs.get(target)
+    }
+
+    /// Cost of every node reached.
+    pub fn costs(&self) -> &HashMap<N, W> {
+        &self.costs
+    }
+
+    /// Cost and nodes of the cheapest path from the start to `target`, both
+    /// included, `None` if it is unreachable.
+    pub fn path(&self, target: &N) -> Option<(W, Vec<N>)> {
+        let cost = self.costs.get(target)?.clone();
+
+        let mut path = vec![target.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some((cost, path))
+    }
+}
+
+/// Cheapest paths from `start` to every node it reaches, by Dijkstra's
+/// algorithm on an indexed binary heap: O((V + E) log V).
+///
+/// `graph` is a `Graph`, or a `HashMap` from each node to its neighbours
+/// and the weights, the shape of `search`. Weights must not be negative;
+/// `W::default()` is taken as zero.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use algorithm::search::dijkstra::dijkstra;
+///
+/// let mut graph = HashMap::new();
+/// let mut start = HashMap::new();
+/// start.insert("a", 6);
+/// start.insert("b", 2);
+/// graph.insert("start", start);
+/// let mut b = HashMap::new();
+/// b.insert("a", 3);
+/// b.insert("fin", 5);
+/// graph.insert("b", b);
+/// let mut a = HashMap::new();
+/// a.insert("fin", 1);
+/// graph.insert("a", a);
+///
+/// let paths = dijkstra(&graph, "start");
+/// assert_eq!(paths.path(&"fin"), Some((6, vec!["start", "b", "a", "fin"])));
+/// assert_eq!(paths.path(&"nowhere"), None);
+/// ```
+pub fn dijkstra<N, W, G>(graph: &G, start: N) -> ShortestPaths<N, W>
+where
+    N: Hash + Eq + Clone,
+    W: Ord + Add<Output = W> + Clone + Default,
+    G: Weighted<N, W>,
+{
+    dijkstra_until(graph, start, |_| false)
+}
+
+/// Like `dijkstra`, stopping as soon as the cost of a node meeting `stop`
+/// is known; the nodes which are not cheaper to reach than it may be left
+/// out.
+pub fn dijkstra_until<N, W, G, F>(graph: &G, start: N, mut stop: F) -> ShortestPaths<N, W>
+where
+    N: Hash + Eq + Clone,
+    W: Ord + Add<Output = W> + Clone + Default,
+    G: Weighted<N, W>,
+    F: FnMut(&N) -> bool,
+{
+    let mut costs = HashMap::new();
+    let mut parents = HashMap::new();
+    // nodes reached but not settled, by their cheapest cost so far
+    let mut queue = IndexedMinPQ::new();
+    queue.push(start.clone(), W::default());
+
+    while let Some((node, cost)) = queue.pop() {
+        costs.insert(node.clone(), cost.clone());
+        if stop(&node) {
+            break;
+        }
+
+        for (next, weight) in graph.weighted(&node) {
+            if costs.contains_key(next) {
+                continue;
+            }
+            let new_cost = cost.clone() + weight.clone();
+            let cheaper = match queue.priority(next) {
+                Some(old) => new_cost < *old,
+                None => true,
+            };
+            if cheaper {
+                parents.insert(next.clone(), node.clone());
+                queue.push(next.clone(), new_cost);
+            }
+        }
+    }
+
+    ShortestPaths {
+        start,
+        costs,
+        parents,
+    }
+}
+
+/// Cost and nodes of the cheapest path from `start` to `target`, searching
+/// no further than needed.
+pub fn shortest_path<N, W, G>(graph: &G, start: N, target: &N) -> Option<(W, Vec<N>)>
+where
+    N: Hash + Eq + Clone,
+    W: Ord + Add<Output = W> + Clone + Default,
+    G: Weighted<N, W>,
+{
+    dijkstra_until(graph, start, |node| node == target).path(target)
+}
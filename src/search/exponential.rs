@@ -0,0 +1,75 @@
+use std::cmp::{Ordering, PartialOrd};
+
+/// Index of an element equal to `target` in a sorted slice, found by
+/// exponential (galloping) search: the elements at 0, 2, 6, 14, 30, ... are
+/// probed until one is not less than `target`, then the last gap is
+/// binary searched. Costs O(log i) for a match at `i`, so it suits targets
+/// near the front of huge slices.
+///
+/// # Example
+/// ```
+/// use algorithm::search::exponential::exponential_search;
+///
+/// let source: Vec<u32> = (0..1000).map(|x| x * 2).collect();
+/// assert_eq!(exponential_search(&source, &10), Some(5));
+/// assert_eq!(exponential_search(&source, &11), None);
+/// ```
+pub fn exponential_search<T: PartialOrd>(source: &[T], target: &T) -> Option<usize> {
+    exponential_search_by(|i| source.get(i).map(|x| order(x, target)))
+}
+
+/// Like `exponential_search`, over a sorted source of unknown length.
+///
+/// `compare(i)` tells how the element at `i` orders relative to the one
+/// looked for, or `None` past the end of the source.
+///
+/// # Example
+/// ```
+/// use algorithm::search::exponential::exponential_search_by;
+///
+/// // the squares, without an end
+/// assert_eq!(exponential_search_by(|i| Some((i * i).cmp(&144))), Some(12));
+/// assert_eq!(exponential_search_by(|i| Some((i * i).cmp(&150))), None);
+/// ```
+pub fn exponential_search_by<F>(mut compare: F) -> Option<usize>
+where
+    F: FnMut(usize) -> Option<Ordering>,
+{
+    // gallop until the element at `hi - 1` is not less than the target
+    let mut lo = 0;
+    let mut hi = 1;
+    loop {
+        match compare(hi - 1) {
+            Some(Ordering::Less) => {
+                lo = hi;
+                hi = 2 * hi + 1;
+            }
+            Some(Ordering::Equal) => return Some(hi - 1),
+            _ => break,
+        }
+    }
+
+    // a match is in [lo, hi - 1)
+    let mut hi = hi - 1;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match compare(mid) {
+            Some(Ordering::Less) => lo = mid + 1,
+            Some(Ordering::Equal) => return Some(mid),
+            _ => hi = mid,
+        }
+    }
+    None
+}
+
+/// How `element` orders relative to `target`, treating incomparable
+/// elements as equal.
+pub(crate) fn order<T: PartialOrd>(element: &T, target: &T) -> Ordering {
+    if element < target {
+        Ordering::Less
+    } else if target < element {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
@@ -0,0 +1,59 @@
+use std::cmp::{Ordering, PartialOrd};
+
+use search::exponential::order;
+
+/// Index of an element equal to `target` in a sorted slice, found by
+/// Fibonacci search: like binary search, but the slice is split at
+/// Fibonacci numbers, so the probe positions only take additions and
+/// subtractions to compute. About `1.44 * log2(n)` probes.
+///
+/// # Example
+/// ```
+/// use algorithm::search::fibonacci::fibonacci_search;
+///
+/// let source = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+/// assert_eq!(fibonacci_search(&source, &13), Some(5));
+/// assert_eq!(fibonacci_search(&source, &4), None);
+/// ```
+pub fn fibonacci_search<T: PartialOrd>(source: &[T], target: &T) -> Option<usize> {
+    let len = source.len();
+
+    // the smallest Fibonacci number `fib` not less than `len`, and the two
+    // before it
+    let mut fib2 = 0;
+    let mut fib1 = 1;
+    let mut fib = 1;
+    while fib < len {
+        fib2 = fib1;
+        fib1 = fib;
+        fib = fib1 + fib2;
+    }
+
+    // every element before `offset` is less than the target
+    let mut offset = 0;
+    while fib > 1 {
+        let i = (offset + fib2).min(len) - 1;
+        match order(&source[i], target) {
+            Ordering::Less => {
+                // continue in the larger part, of length fib1
+                fib = fib1;
+                fib1 = fib2;
+                fib2 = fib - fib1;
+                offset = i + 1;
+            }
+            Ordering::Greater => {
+                // continue in the smaller part, of length fib2
+                fib = fib2;
+                fib1 -= fib2;
+                fib2 = fib - fib1;
+            }
+            Ordering::Equal => return Some(i),
+        }
+    }
+
+    if fib1 == 1 && offset < len && order(&source[offset], target) == Ordering::Equal {
+        Some(offset)
+    } else {
+        None
+    }
+}
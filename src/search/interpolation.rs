@@ -0,0 +1,110 @@
+use std::cmp::PartialOrd;
+
+/// Index of an element equal to `target` in a sorted slice, found by
+/// interpolation search: the next probe is where `target` would be if the
+/// values between the ends of the range were evenly spread.
+///
+/// On uniformly distributed keys that takes O(log log n) probes. When the
+/// distribution is skewed, any two probes which fail to halve the range
+/// are followed by a plain binary search step, so it never takes more
+/// than about three times the probes of `binary_search`.
+///
+/// # Example
+/// ```
+/// use algorithm::search::interpolation::interpolation_search;
+///
+/// let source: Vec<u32> = (0..1000).map(|x| x * 3).collect();
+/// assert_eq!(interpolation_search(&source, &2997), Some(999));
+/// assert_eq!(interpolation_search(&source, &1000), None);
+/// ```
+pub fn interpolation_search<T>(source: &[T], target: &T) -> Option<usize>
+where
+    T: PartialOrd + Copy + Into<f64>,
+{
+    interpolation_search_by_key(source, target, |&x| x.into())
+}
+
+/// Like `interpolation_search`, interpolating between the positions
+/// `key` maps the elements to, e.g. `|&x| x as f64` for `u64`, `i64` or
+/// `usize`, which do not convert to `f64` losslessly. `key` must not
+/// decrease along the slice; the elements are still compared as `T`.
+///
+/// # Example
+/// ```
+/// use algorithm::search::interpolation::interpolation_search_by_key;
+///
+/// let source: Vec<u64> = (0..1000).map(|x| x << 40).collect();
+/// assert_eq!(interpolation_search_by_key(&source, &(999 << 40), |&x| x as f64), Some(999));
+/// assert_eq!(interpolation_search_by_key(&source, &1, |&x| x as f64), None);
+/// ```
+pub fn interpolation_search_by_key<T, F>(source: &[T], target: &T, mut key: F) -> Option<usize>
+where
+    T: PartialOrd,
+    F: FnMut(&T) -> f64,
+{
+    if source.is_empty() {
+        return None;
+    }
+    let t = key(target);
+
+    // a match is in [lo, hi]
+    let mut lo = 0;
+    let mut hi = source.len() - 1;
+    // width of the range two interpolations ago
+    let mut checkpoint = hi - lo + 1;
+    let mut steps = 0;
+    while lo <= hi {
+        let low = key(&source[lo]);
+        let high = key(&source[hi]);
+        let i = if high > low {
+            // clamped, the target may be out of the range
+            let fraction = ((t - low) / (high - low)).clamp(0.0, 1.0);
+            lo + (fraction * (hi - lo) as f64) as usize
+        } else {
+            lo
+        };
+        if let Some(found) = narrow(source, target, i, &mut lo, &mut hi) {
+            return Some(found);
+        }
+
+        steps += 1;
+        if lo <= hi && steps == 2 {
+            if 2 * (hi - lo + 1) > checkpoint {
+                // interpolation did not pay off, bisect once
+                let mid = lo + (hi - lo) / 2;
+                if let Some(found) = narrow(source, target, mid, &mut lo, &mut hi) {
+                    return Some(found);
+                }
+            }
+            checkpoint = hi + 1 - lo;
+            steps = 0;
+        }
+    }
+    None
+}
+
+/// Probe `source[i]`, returning `i` on a match, or else moving `lo` or
+/// `hi` past it (`hi < lo` once the range is empty).
+fn narrow<T: PartialOrd>(
+    source: &[T],
+    target: &T,
+    i: usize,
+    lo: &mut usize,
+    hi: &mut usize,
+) -> Option<usize> {
+    if source[i] < *target {
+        *lo = i + 1;
+        None
+    } else if *target < source[i] {
+        if i == 0 {
+            // nothing is left below, make the range empty
+            *lo = 1;
+            *hi = 0;
+        } else {
+            *hi = i - 1;
+        }
+        None
+    } else {
+        Some(i)
+    }
+}
@@ -0,0 +1,37 @@
+use std::cmp::{Ordering, PartialOrd};
+
+/// Index of an element equal to `target` in a sorted slice, found by jump
+/// search: blocks of `sqrt(len)` elements are skipped while their last
+/// element is less than `target`, then the block reached is scanned.
+///
+/// Costs O(sqrt(n)) probes, but only ever steps forward, which suits
+/// sources that are expensive to step back in.
+///
+/// # Example
+/// ```
+/// use algorithm::search::jump::jump_search;
+///
+/// let source = [1, 3, 5, 7, 9, 11, 13];
+/// assert_eq!(jump_search(&source, &9), Some(4));
+/// assert_eq!(jump_search(&source, &4), None);
+/// ```
+pub fn jump_search<T: PartialOrd>(source: &[T], target: &T) -> Option<usize> {
+    let len = source.len();
+    let mut step = 1;
+    while step * step < len {
+        step += 1;
+    }
+
+    // skip whole blocks
+    let mut start = 0;
+    while start + step < len && source[start + step - 1] < *target {
+        start += step;
+    }
+
+    for (i, x) in source.iter().enumerate().skip(start) {
+        if x.partial_cmp(target) != Some(Ordering::Less) {
+            return if *target < *x { None } else { Some(i) };
+        }
+    }
+    None
+}
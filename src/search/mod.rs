@@ -1,4 +1,9 @@
 pub mod binary;
 pub mod breadth;
+pub mod depth;
 pub mod dijkstra;
-pub mod greedy;
\ No newline at end of file
+pub mod exponential;
+pub mod fibonacci;
+pub mod greedy;
+pub mod interpolation;
+pub mod jump;
\ No newline at end of file
@@ -0,0 +1,381 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::Arc;
+
+use rand::{thread_rng, Rng};
+
+use sort::bucket::bucket_sort_by_key;
+use sort::counting::counting_sort;
+use sort::heap::heap_sort;
+use sort::insertion::{binary_insertion_sort, insertion_sort};
+use sort::intro::intro_sort;
+use sort::merge::{merge_sort, merge_sort_bottom_up};
+use sort::parallel::{par_merge_sort, par_quick_sort};
+use sort::quick::{quick_sort, quick_sort_by};
+use sort::radix::{msd_radix_sort, radix_sort, RadixKey};
+use sort::select::selection_sort;
+use sort::shell::shell_sort;
+use sort::tim::tim_sort;
+
+/// Comparisons made by one sort, and how often its elements were found
+/// relocated.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Measurement {
+    pub comparisons: usize,
+    /// Times an element was found at another address than where it was
+    /// last seen. This is not the number of moves made: see `Counted`.
+    pub relocations: usize,
+}
+
+struct Counters {
+    comparisons: AtomicUsize,
+    relocations: AtomicUsize,
+}
+
+/// Element wrapper counting the comparisons made on it and its
+/// relocations.
+///
+/// Rust moves are plain copies which the elements cannot hook, so the
+/// moves themselves are not counted. A relocation is counted instead when
+/// an element is seen (compared, or checked after the sort) at another
+/// address than the last time, a clone counting as one when first seen.
+/// An element moved several times between two sightings is relocated
+/// once, and one moved back to where it was last seen (e.g. through a
+/// temporary) not at all.
+pub struct Counted<T> {
+    value: T,
+    address: AtomicUsize,
+    counters: Arc<Counters>,
+}
+
+impl<T> Counted<T> {
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    fn observe(&self) {
+        let address = self as *const Counted<T> as usize;
+        if self.address.swap(address, AtomicOrdering::Relaxed) != address {
+            self.counters.relocations.fetch_add(1, AtomicOrdering::Relaxed);
+        }
+    }
+
+    fn compared(&self, other: &Counted<T>) {
+        self.observe();
+        other.observe();
+        self.counters.comparisons.fetch_add(1, AtomicOrdering::Relaxed);
+    }
+}
+
+impl<T: Clone> Clone for Counted<T> {
+    fn clone(&self) -> Self {
+        Counted {
+            value: self.value.clone(),
+            // a copy, counted as a move wherever it is first seen
+            address: AtomicUsize::new(0),
+            counters: self.counters.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Counted<T> {
+    fn eq(&self, other: &Counted<T>) -> bool {
+        self.compared(other);
+        self.value == other.value
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Counted<T> {
+    fn partial_cmp(&self, other: &Counted<T>) -> Option<Ordering> {
+        self.compared(other);
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Counted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: RadixKey> RadixKey for Counted<T> {
+    fn key_len(&self) -> usize {
+        self.observe();
+        self.value.key_len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self.value.key_byte(index)
+    }
+}
+
+/// Run `sort` on a counted copy of `input`, returning what it cost and
+/// the sorted values.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::analysis::measure;
+/// use algorithm::sort::insertion::insertion_sort;
+///
+/// let (measurement, sorted) = measure(&[3, 2, 1], |s| insertion_sort(s));
+/// assert_eq!(sorted, vec![1, 2, 3]);
+/// assert_eq!(measurement.comparisons, 3);
+/// // three swaps, each relocating two elements
+/// assert_eq!(measurement.relocations, 6);
+/// ```
+pub fn measure<T, F>(input: &[T], sort: F) -> (Measurement, Vec<T>)
+where
+    T: Clone,
+    F: FnOnce(&mut [Counted<T>]),
+{
+    let counters = Arc::new(Counters {
+        comparisons: AtomicUsize::new(0),
+        relocations: AtomicUsize::new(0),
+    });
+    let mut source: Vec<Counted<T>> = input
+        .iter()
+        .map(|v| Counted {
+            value: v.clone(),
+            address: AtomicUsize::new(0),
+            counters: counters.clone(),
+        })
+        .collect();
+    // the elements are in their initial places now
+    for element in &source {
+        element.address.store(element as *const Counted<T> as usize, AtomicOrdering::Relaxed);
+    }
+
+    sort(&mut source);
+    for element in &source {
+        element.observe();
+    }
+
+    let measurement = Measurement {
+        comparisons: counters.comparisons.load(AtomicOrdering::Relaxed),
+        relocations: counters.relocations.load(AtomicOrdering::Relaxed),
+    };
+    (measurement, source.into_iter().map(Counted::into_inner).collect())
+}
+
+/// Shapes of input to measure the sorts on.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Input {
+    /// Uniformly random values.
+    Random,
+    /// `0, 1, 2, ...`
+    Sorted,
+    /// `..., 2, 1, 0`
+    Reversed,
+    /// Ascending up to the middle, then descending.
+    OrganPipe,
+    /// Random values out of 8 distinct ones.
+    FewUnique,
+    /// Ascending runs of length `sqrt(len)`.
+    Sawtooth,
+    /// McIlroy's adversary against `quick_sort`, see `antiqsort`.
+    AntiQuickSort,
+}
+
+impl Input {
+    pub fn all() -> Vec<Input> {
+        vec![
+            Input::Random,
+            Input::Sorted,
+            Input::Reversed,
+            Input::OrganPipe,
+            Input::FewUnique,
+            Input::Sawtooth,
+            Input::AntiQuickSort,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Input::Random => "random",
+            Input::Sorted => "sorted",
+            Input::Reversed => "reversed",
+            Input::OrganPipe => "organ pipe",
+            Input::FewUnique => "few unique",
+            Input::Sawtooth => "sawtooth",
+            Input::AntiQuickSort => "antiqsort",
+        }
+    }
+
+    pub fn generate(&self, len: usize) -> Vec<u64> {
+        let mut rng = thread_rng();
+        match *self {
+            Input::Random => (0..len).map(|_| rng.gen()).collect(),
+            Input::Sorted => (0..len as u64).collect(),
+            Input::Reversed => (0..len as u64).rev().collect(),
+            Input::OrganPipe => (0..len)
+                .map(|i| if i < len / 2 { i as u64 } else { (len - 1 - i) as u64 })
+                .collect(),
+            Input::FewUnique => (0..len).map(|_| rng.gen_range(0, 8)).collect(),
+            Input::Sawtooth => {
+                let mut tooth = 1;
+                while tooth * tooth < len {
+                    tooth += 1;
+                }
+                (0..len).map(|i| (i % tooth) as u64).collect()
+            }
+            Input::AntiQuickSort => antiqsort(len),
+        }
+    }
+}
+
+/// Input on which `quick_sort` goes quadratic, built by McIlroy's
+/// "A Killer Adversary for Quicksort".
+///
+/// `quick_sort` is run on values which are all "gas" (undecided) at
+/// first. Whenever two gas values are compared one of them is frozen to
+/// the next smallest value, preferring the one which looks like the
+/// pivot, so every pivot ends up among the smallest values left. As the
+/// pivot choice only depends on the comparisons, replaying the frozen
+/// values makes `quick_sort` take the same quadratic path.
+pub fn antiqsort(len: usize) -> Vec<u64> {
+    let gas = len as u64;
+    let mut values = vec![gas; len];
+    let mut solid = 0;
+    let mut candidate = 0;
+
+    let mut indices: Vec<usize> = (0..len).collect();
+    quick_sort_by(&mut indices, |&x, &y| {
+        if values[x] == gas && values[y] == gas {
+            let frozen = if x == candidate { x } else { y };
+            values[frozen] = solid;
+            solid += 1;
+        }
+        if values[x] == gas {
+            candidate = x;
+        } else if values[y] == gas {
+            candidate = y;
+        }
+        values[x].cmp(&values[y])
+    });
+    values
+}
+
+/// A sort measured by `report`.
+pub type CountedSort = fn(&mut [Counted<u64>]);
+
+/// Every in-memory sort of the crate, by name, as `Report::all` measures
+/// them.
+///
+/// The counting, radix and bucket sorts order by keys rather than by
+/// comparing elements, so they make no comparisons. `ExternalSort` is
+/// left out: its elements go through files, where they cannot be counted.
+pub fn sorts() -> Vec<(&'static str, CountedSort)> {
+    vec![
+        ("quick_sort", quick_sort),
+        ("intro_sort", intro_sort),
+        ("merge_sort", merge_sort),
+        ("merge_sort_bottom_up", merge_sort_bottom_up),
+        ("tim_sort", tim_sort),
+        ("heap_sort", heap_sort),
+        ("shell_sort", shell_sort),
+        ("insertion_sort", insertion_sort),
+        ("binary_insertion_sort", binary_insertion_sort),
+        ("selection_sort", selection_sort),
+        ("radix_sort", radix_sort),
+        ("msd_radix_sort", msd_radix_sort),
+        ("counting_sort", counting_sort),
+        ("bucket_sort", |s| bucket_sort_by_key(s, |v| *v.value() as f64)),
+        ("par_merge_sort", par_merge_sort),
+        ("par_quick_sort", par_quick_sort),
+    ]
+}
+
+/// One line of a `Report`.
+#[derive(Clone, Debug)]
+pub struct Row {
+    pub algorithm: String,
+    pub input: String,
+    pub len: usize,
+    pub measurement: Measurement,
+}
+
+/// Table of measurements per algorithm and input shape.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::analysis::{Input, Report};
+/// use algorithm::sort::heap::heap_sort;
+///
+/// let mut report = Report::new();
+/// report.measure("heap_sort", heap_sort, Input::Sorted, 100);
+/// assert_eq!(report.rows().len(), 1);
+/// println!("{}", report);
+/// ```
+pub struct Report {
+    rows: Vec<Row>,
+}
+
+impl Default for Report {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Report { rows: Vec::new() }
+    }
+
+    /// Measure every sort of `sorts()` on every `Input` of length `len`.
+    pub fn all(len: usize) -> Self {
+        let mut report = Report::new();
+        for input in Input::all() {
+            let source = input.generate(len);
+            for (name, sort) in sorts() {
+                report.add(name, input.name(), len, measure(&source, sort).0);
+            }
+        }
+        report
+    }
+
+    /// Measure `sort` on a new input of shape `input` and length `len`.
+    pub fn measure(&mut self, algorithm: &str, sort: CountedSort, input: Input, len: usize) {
+        let source = input.generate(len);
+        self.add(algorithm, input.name(), len, measure(&source, sort).0);
+    }
+
+    pub fn add(&mut self, algorithm: &str, input: &str, len: usize, measurement: Measurement) {
+        self.rows.push(Row {
+            algorithm: algorithm.to_string(),
+            input: input.to_string(),
+            len,
+            measurement,
+        });
+    }
+
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<24}{:<14}{:>10}{:>14}{:>14}",
+            "algorithm", "input", "len", "comparisons", "relocations"
+        )?;
+        for row in &self.rows {
+            writeln!(
+                f,
+                "{:<24}{:<14}{:>10}{:>14}{:>14}",
+                row.algorithm,
+                row.input,
+                row.len,
+                row.measurement.comparisons,
+                row.measurement.relocations
+            )?;
+        }
+        Ok(())
+    }
+}
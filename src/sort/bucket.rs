@@ -0,0 +1,68 @@
+use sort::insertion::insertion_sort_by_less;
+use sort::radix::apply_permutation;
+
+/// Bucket sort for floats: the range between the smallest and the largest
+/// value is split into `len` equal buckets, each sorted by insertion sort.
+///
+/// Expected O(n) when the values are uniformly distributed, O(n^2) when
+/// they all land in a few buckets. The values must be finite.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::bucket::bucket_sort;
+///
+/// let mut source = vec![0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51];
+/// bucket_sort(&mut source);
+/// assert_eq!(source, vec![0.23, 0.25, 0.32, 0.42, 0.47, 0.51, 0.52]);
+/// ```
+pub fn bucket_sort<T: Copy + PartialOrd + Into<f64>>(source: &mut [T]) {
+    bucket_sort_by_key(source, |&v| v);
+}
+
+/// Like `bucket_sort`, ordering elements by the float key `key` extracts.
+/// Each key is extracted once, and the sort is stable.
+pub fn bucket_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    K: Into<f64>,
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<f64> = source.iter().map(|v| key(v).into()).collect();
+    let order = bucket_order(&keys);
+    apply_permutation(source, &order);
+}
+
+/// Indices of `keys` in stably sorted order, by bucket sort.
+fn bucket_order(keys: &[f64]) -> Vec<usize> {
+    let len = keys.len();
+    let mut min = f64::INFINITY;
+    let mut max = f64::NEG_INFINITY;
+    for &k in keys {
+        if k < min {
+            min = k;
+        }
+        if k > max {
+            max = k;
+        }
+    }
+    if len < 2 || min == max {
+        return (0..len).collect();
+    }
+
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); len];
+    for (i, &k) in keys.iter().enumerate() {
+        let position = (k - min) / (max - min) * len as f64;
+        let b = if (position as usize) < len {
+            position as usize
+        } else {
+            len - 1
+        };
+        buckets[b].push(i);
+    }
+
+    let mut order = Vec::with_capacity(len);
+    for mut bucket in buckets {
+        insertion_sort_by_less(&mut bucket, &mut |&a: &usize, &b: &usize| keys[a] < keys[b]);
+        order.extend(bucket);
+    }
+    order
+}
@@ -0,0 +1,35 @@
+use std::cmp::PartialOrd;
+
+use sort::radix::apply_permutation;
+use sort::tim;
+
+/// Stable sort by a key which is expensive to compute (decorate, sort,
+/// undecorate, a.k.a. the Schwartzian transform).
+///
+/// `key` is called exactly once per element, instead of twice per
+/// comparison as in the `_by_key` sorts. The keys are sorted with
+/// `tim_sort` and the elements then moved into place without cloning.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::cached::sort_by_cached_key;
+///
+/// let mut source = vec![-5i32, 4, 1, -3, 2];
+/// let mut calls = 0;
+/// sort_by_cached_key(&mut source, |x| {
+///     calls += 1;
+///     x.to_string()
+/// });
+/// assert_eq!(source, vec![-3, -5, 1, 2, 4]);
+/// assert_eq!(calls, 5);
+/// ```
+pub fn sort_by_cached_key<T, K, F>(source: &mut [T], key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<K> = source.iter().map(key).collect();
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    tim::sort_by_less(&mut order, &mut |&a: &usize, &b: &usize| keys[a] < keys[b]);
+    apply_permutation(source, &order);
+}
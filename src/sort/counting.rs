@@ -0,0 +1,79 @@
+use sort::radix::{apply_permutation, lsd_order, RadixKey};
+
+/// Key ranges wider than this many times the number of keys are sorted by
+/// LSD radix sort instead, rather than allocating a count per value.
+const MAX_RANGE_PER_KEY: u64 = 4;
+
+/// Stable counting sort for keys of at most 8 bytes (integers, floats),
+/// O(n + range) where `range` is the distance between the smallest and the
+/// largest key, so it only pays off when the keys are dense. Sparse keys,
+/// whose range is more than a few times their number, fall back to LSD
+/// radix sort.
+///
+/// # Panics
+/// If a key is longer than 8 bytes.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::counting::counting_sort;
+///
+/// let mut source = vec![3u8, 1, 2, 3, 1, 0];
+/// counting_sort(&mut source);
+/// assert_eq!(source, vec![0, 1, 1, 2, 3, 3]);
+/// ```
+pub fn counting_sort<T: RadixKey>(source: &mut [T]) {
+    let order = counting_order(source);
+    apply_permutation(source, &order);
+}
+
+/// Like `counting_sort`, ordering elements by the key `key` extracts.
+/// Each key is extracted once.
+pub fn counting_sort_by_key<T, K, F>(source: &mut [T], key: F)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<K> = source.iter().map(key).collect();
+    let order = counting_order(&keys);
+    apply_permutation(source, &order);
+}
+
+/// Indices of `keys` in stably sorted order, by counting sort.
+pub(crate) fn counting_order<K: RadixKey>(keys: &[K]) -> Vec<usize> {
+    let values: Vec<u64> = keys.iter().map(|k| to_u64(k)).collect();
+    let min = match values.iter().min() {
+        Some(&min) => min,
+        None => return Vec::new(),
+    };
+    let max = *values.iter().max().unwrap();
+
+    // in the unsigned domain, so `max - min` cannot overflow
+    let range = max - min;
+    if range / MAX_RANGE_PER_KEY > values.len() as u64 {
+        return lsd_order(keys);
+    }
+
+    let mut counts = vec![0usize; range as usize + 2];
+    for &v in &values {
+        counts[(v - min) as usize + 1] += 1;
+    }
+    for i in 1..counts.len() {
+        counts[i] += counts[i - 1];
+    }
+
+    // counts[v - min] is the next position of key v
+    let mut order = vec![0; values.len()];
+    for (i, &v) in values.iter().enumerate() {
+        let slot = &mut counts[(v - min) as usize];
+        order[*slot] = i;
+        *slot += 1;
+    }
+    order
+}
+
+/// The key as a big-endian number, which keeps its order.
+fn to_u64<K: RadixKey>(key: &K) -> u64 {
+    let len = key.key_len();
+    assert!(len <= 8, "counting sort keys must fit in 8 bytes, got {}", len);
+    (0..len).fold(0, |acc, i| (acc << 8) | key.key_byte(i) as u64)
+}
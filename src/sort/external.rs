@@ -0,0 +1,562 @@
+use std::cmp::{max, min, Ordering};
+use std::env;
+use std::error::Error;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::mem;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json;
+
+use heap::binary_heap::BinaryHeap;
+use sort::radix::apply_permutation;
+use sort::tim;
+
+/// Runs merged at once by default, well below the usual limits on open
+/// files.
+pub const DEFAULT_FAN_IN: usize = 256;
+
+/// Completed runs, one `<file name> <record count>` line each.
+const MANIFEST: &str = "runs";
+/// New manifest, renamed over the old one once complete.
+const MANIFEST_UPDATE: &str = "runs.new";
+
+static TEMP_DIR_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Directory which is cleaned up when dropped, unless told to keep it.
+///
+/// A directory `TempDir` created is removed with everything in it. One
+/// which existed before is left in place, and only the files registered
+/// with `track` are removed from it.
+pub struct TempDir {
+    path: PathBuf,
+    created: bool,
+    files: Vec<String>,
+    keep: bool,
+}
+
+impl TempDir {
+    /// Create a new, uniquely named directory under `env::temp_dir()`.
+    pub fn new() -> io::Result<Self> {
+        loop {
+            let name = format!(
+                "algorithm-sort-{}-{}",
+                process::id(),
+                TEMP_DIR_COUNTER.fetch_add(1, AtomicOrdering::SeqCst)
+            );
+            let path = env::temp_dir().join(name);
+            match fs::create_dir(&path) {
+                Ok(()) => return Ok(TempDir::with_path(path, true)),
+                Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Use `path` as the directory, creating it if it does not exist yet.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let created = !path.as_ref().exists();
+        fs::create_dir_all(path.as_ref())?;
+        Ok(TempDir::with_path(path.as_ref().to_path_buf(), created))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether the directory is left in place when dropped.
+    pub fn keep_on_drop(&mut self, keep: bool) {
+        self.keep = keep;
+    }
+
+    /// Remove the file `name` in the directory when dropped, returning its
+    /// path.
+    pub fn track(&mut self, name: &str) -> PathBuf {
+        if !self.files.iter().any(|file| file == name) {
+            self.files.push(name.to_string());
+        }
+        self.path.join(name)
+    }
+
+    fn with_path(path: PathBuf, created: bool) -> Self {
+        TempDir {
+            path,
+            created,
+            files: Vec::new(),
+            keep: false,
+        }
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        if self.keep {
+            return;
+        }
+        if self.created {
+            let _ = fs::remove_dir_all(&self.path);
+        } else {
+            for file in &self.files {
+                let _ = fs::remove_file(self.path.join(file));
+            }
+        }
+    }
+}
+
+/// A sorted run spilled to disk.
+struct Run {
+    name: String,
+    len: usize,
+}
+
+/// External (out-of-core) merge sort for inputs larger than memory.
+///
+/// The input is read in chunks of about `memory_budget` bytes, each chunk
+/// is sorted with `tim_sort` and spilled to a run file in the temp
+/// directory (one JSON encoded record per line), and the runs are then
+/// merged with a k-way heap merge. The sort is stable.
+///
+/// At most `fan_in` runs are open at once: while there are more, groups of
+/// `fan_in` consecutive runs are merged into intermediate runs, until a
+/// final merge of all the runs left is possible.
+///
+/// Every completed run is recorded in a manifest, and so is every
+/// intermediate run, in place of the runs it replaces. If a sort fails or
+/// the process dies, the temp directory is kept, and `ExternalSort::resume`
+/// on it continues with the same input where the completed runs end. The
+/// run files and the manifest are removed when an `ExternalSort` is
+/// dropped after a successful sort, and so is the directory if the
+/// `ExternalSort` created it.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::external::ExternalSort;
+///
+/// let mut sorter = ExternalSort::new(64).unwrap();
+/// let mut sorted = Vec::new();
+/// sorter
+///     .sort(vec![5, 3, 9, 1, 7, 2], |x: i32| {
+///         sorted.push(x);
+///         Ok(())
+///     })
+///     .unwrap();
+/// assert_eq!(sorted, vec![1, 2, 3, 5, 7, 9]);
+/// ```
+pub struct ExternalSort {
+    dir: TempDir,
+    memory_budget: usize,
+    fan_in: usize,
+    runs: Vec<Run>,
+    /// Number of the next run file.
+    next_run: usize,
+}
+
+impl ExternalSort {
+    /// Sorter using a fresh temp directory and chunks of about
+    /// `memory_budget` bytes.
+    pub fn new(memory_budget: usize) -> io::Result<Self> {
+        Ok(ExternalSort {
+            dir: TempDir::new()?,
+            memory_budget,
+            fan_in: DEFAULT_FAN_IN,
+            runs: Vec::new(),
+            next_run: 0,
+        })
+    }
+
+    /// Sorter continuing from the runs completed in `dir` by an earlier,
+    /// interrupted sort (or starting afresh if there are none).
+    ///
+    /// Fails with `InvalidInput` if `dir` has files but no manifest, as it
+    /// is not the directory of a sort. Only the files of the sort are
+    /// removed from `dir`, and `dir` itself only if it did not exist.
+    pub fn resume<P: AsRef<Path>>(dir: P, memory_budget: usize) -> io::Result<Self> {
+        let path = dir.as_ref();
+        let manifest = path.join(MANIFEST);
+        if !manifest.exists() && path.exists() && fs::read_dir(path)?.next().is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is not empty and has no manifest", path.display()),
+            ));
+        }
+
+        let mut dir = TempDir::open(path)?;
+        // leave the runs alone if the manifest does not check out
+        dir.keep_on_drop(true);
+        let mut runs = Vec::new();
+        let mut next_run = 0;
+        if manifest.exists() {
+            dir.track(MANIFEST);
+            dir.track(MANIFEST_UPDATE);
+            for line in BufReader::new(File::open(&manifest)?).lines() {
+                let line = line?;
+                let mut fields = line.split_whitespace();
+                let run = match (fields.next(), fields.next().map(|len| len.parse())) {
+                    (Some(name), Some(Ok(len))) => Run {
+                        name: name.to_string(),
+                        len,
+                    },
+                    _ => return Err(invalid_data(format!("bad manifest line: {}", line))),
+                };
+                match run.name.trim_start_matches("run-").parse::<usize>() {
+                    Ok(number) => next_run = max(next_run, number + 1),
+                    Err(_) => return Err(invalid_data(format!("bad run name: {}", run.name))),
+                }
+                if !dir.track(&run.name).exists() {
+                    return Err(invalid_data(format!("missing run file {}", run.name)));
+                }
+                runs.push(run);
+            }
+        }
+        dir.keep_on_drop(false);
+
+        Ok(ExternalSort {
+            dir,
+            memory_budget,
+            fan_in: DEFAULT_FAN_IN,
+            runs,
+            next_run,
+        })
+    }
+
+    pub fn temp_dir(&self) -> &Path {
+        self.dir.path()
+    }
+
+    pub fn memory_budget(&self) -> usize {
+        self.memory_budget
+    }
+
+    /// Most runs merged at once, `DEFAULT_FAN_IN` unless set.
+    pub fn fan_in(&self) -> usize {
+        self.fan_in
+    }
+
+    /// Merge at most `fan_in` runs at once.
+    ///
+    /// # Panics
+    /// If `fan_in` is less than 2.
+    pub fn set_fan_in(&mut self, fan_in: usize) {
+        assert!(fan_in >= 2, "the fan-in must be at least 2");
+        self.fan_in = fan_in;
+    }
+
+    /// Number of runs currently spilled to disk.
+    pub fn run_count(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Sort `input`, passing the records to `output` in order.
+    pub fn sort<T, I, F>(&mut self, input: I, output: F) -> io::Result<()>
+    where
+        T: Serialize + DeserializeOwned + PartialOrd,
+        I: IntoIterator<Item = T>,
+        F: FnMut(T) -> io::Result<()>,
+    {
+        let is_less = |a: &T, b: &T| a < b;
+        self.sort_by_less(input.into_iter().map(Ok), &is_less, output)
+    }
+
+    /// Like `sort`, ordering records with `compare`.
+    pub fn sort_by<T, I, C, F>(&mut self, input: I, compare: C, output: F) -> io::Result<()>
+    where
+        T: Serialize + DeserializeOwned,
+        I: IntoIterator<Item = T>,
+        C: Fn(&T, &T) -> Ordering,
+        F: FnMut(T) -> io::Result<()>,
+    {
+        let is_less = |a: &T, b: &T| compare(a, b) == Ordering::Less;
+        self.sort_by_less(input.into_iter().map(Ok), &is_less, output)
+    }
+
+    /// Like `sort`, ordering records by the key `key` extracts.
+    pub fn sort_by_key<T, I, K, G, F>(&mut self, input: I, key: G, output: F) -> io::Result<()>
+    where
+        T: Serialize + DeserializeOwned,
+        I: IntoIterator<Item = T>,
+        K: PartialOrd,
+        G: Fn(&T) -> K,
+        F: FnMut(T) -> io::Result<()>,
+    {
+        let is_less = |a: &T, b: &T| key(a) < key(b);
+        self.sort_by_less(input.into_iter().map(Ok), &is_less, output)
+    }
+
+    /// Sort newline-delimited records (e.g. a text file) byte-wise.
+    pub fn sort_lines<R: BufRead, W: Write>(&mut self, input: R, output: W) -> io::Result<()> {
+        let mut output = output;
+        let is_less = |a: &String, b: &String| a < b;
+        self.sort_by_less(input.lines(), &is_less, |line: String| {
+            output.write_all(line.as_bytes())?;
+            output.write_all(b"\n")
+        })?;
+        output.flush()
+    }
+
+    /// Sort records of exactly `record_len` bytes byte-wise.
+    ///
+    /// Fails with `InvalidData` if the input does not end on a record
+    /// boundary.
+    pub fn sort_fixed<R: Read, W: Write>(
+        &mut self,
+        input: R,
+        record_len: usize,
+        output: W,
+    ) -> io::Result<()> {
+        assert!(record_len > 0, "records must not be empty");
+        let mut output = output;
+        let records = FixedRecords {
+            input,
+            record_len,
+        };
+        let is_less = |a: &Vec<u8>, b: &Vec<u8>| a < b;
+        self.sort_by_less(records, &is_less, |record: Vec<u8>| output.write_all(&record))?;
+        output.flush()
+    }
+
+    fn sort_by_less<T, I, L, F>(&mut self, input: I, is_less: &L, mut output: F) -> io::Result<()>
+    where
+        T: Serialize + DeserializeOwned,
+        I: IntoIterator<Item = io::Result<T>>,
+        L: Fn(&T, &T) -> bool,
+        F: FnMut(T) -> io::Result<()>,
+    {
+        // keep the runs for `resume` unless the sort completes
+        self.dir.keep_on_drop(true);
+
+        let consumed: usize = self.runs.iter().map(|run| run.len).sum();
+        let mut input = input.into_iter().skip(consumed);
+        // each record is encoded once, into the lines of its run
+        let mut encoded = Vec::new();
+        let mut ends = Vec::new();
+        let mut done = false;
+        while !done {
+            let mut chunk = Vec::new();
+            encoded.clear();
+            ends.clear();
+            let mut used = 0;
+            while chunk.is_empty() || used < self.memory_budget {
+                match input.next() {
+                    Some(record) => {
+                        let record = record?;
+                        let start = encoded.len();
+                        serde_json::to_writer(&mut encoded, &record).map_err(invalid_data)?;
+                        encoded.push(b'\n');
+                        ends.push(encoded.len());
+                        // the encoding, and as much again as an estimate of
+                        // the record's own size in memory
+                        used += mem::size_of::<T>() + 2 * (encoded.len() - start);
+                        chunk.push(record);
+                    }
+                    None => {
+                        done = true;
+                        break;
+                    }
+                }
+            }
+            // sorting indices keeps `T: Clone` out of the bounds
+            let mut order: Vec<usize> = (0..chunk.len()).collect();
+            tim::sort_by_less(&mut order, &mut |&a: &usize, &b: &usize| {
+                is_less(&chunk[a], &chunk[b])
+            });
+
+            if done && self.runs.is_empty() {
+                // everything fit in memory
+                apply_permutation(&mut chunk, &order);
+                for record in chunk {
+                    output(record)?;
+                }
+                return self.finish();
+            }
+            if !chunk.is_empty() {
+                self.spill(&encoded, &ends, &order)?;
+            }
+        }
+
+        while self.runs.len() > self.fan_in {
+            self.merge_pass(is_less)?;
+        }
+        self.merge(0..self.runs.len(), is_less, &mut |record, _| output(record))?;
+        self.finish()
+    }
+
+    /// Write the records encoded in `encoded`, which end at `ends`, to a
+    /// new run file in the order `order`, and record it in the manifest.
+    fn spill(&mut self, encoded: &[u8], ends: &[usize], order: &[usize]) -> io::Result<()> {
+        let name = self.next_run_name();
+        let mut writer = BufWriter::new(File::create(self.dir.track(&name))?);
+        for &i in order {
+            let start = if i == 0 { 0 } else { ends[i - 1] };
+            writer.write_all(&encoded[start..ends[i]])?;
+        }
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+
+        // only a run in the manifest counts as done
+        let mut manifest = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.dir.track(MANIFEST))?;
+        writeln!(manifest, "{} {}", name, order.len())?;
+        manifest.sync_all()?;
+
+        self.runs.push(Run {
+            name,
+            len: order.len(),
+        });
+        Ok(())
+    }
+
+    /// Merge every group of `fan_in` consecutive runs into one run, which
+    /// takes the place of the group in the manifest.
+    fn merge_pass<T, L>(&mut self, is_less: &L) -> io::Result<()>
+    where
+        T: DeserializeOwned,
+        L: Fn(&T, &T) -> bool,
+    {
+        let mut start = 0;
+        while start + 1 < self.runs.len() {
+            let end = min(start + self.fan_in, self.runs.len());
+            let name = self.next_run_name();
+            let mut writer = BufWriter::new(File::create(self.dir.track(&name))?);
+            // the records are copied as they were encoded
+            self.merge(start..end, is_less, &mut |_, line| {
+                writer.write_all(line.as_bytes())
+            })?;
+            writer.flush()?;
+            writer.get_ref().sync_all()?;
+
+            let len = self.runs[start..end].iter().map(|run| run.len).sum();
+            let merged: Vec<Run> = self
+                .runs
+                .splice(start..end, Some(Run { name, len }))
+                .collect();
+            self.write_manifest()?;
+            for run in merged {
+                fs::remove_file(self.dir.path().join(&run.name))?;
+            }
+            start += 1;
+        }
+        Ok(())
+    }
+
+    /// Replace the manifest with one listing the current runs.
+    fn write_manifest(&mut self) -> io::Result<()> {
+        let update = self.dir.track(MANIFEST_UPDATE);
+        let mut writer = BufWriter::new(File::create(&update)?);
+        for run in &self.runs {
+            writeln!(writer, "{} {}", run.name, run.len)?;
+        }
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        // a rename replaces the manifest at once, a crash leaves either one
+        fs::rename(&update, self.dir.track(MANIFEST))
+    }
+
+    fn next_run_name(&mut self) -> String {
+        self.next_run += 1;
+        format!("run-{:06}", self.next_run - 1)
+    }
+
+    /// K-way merge of the runs in `runs`, passing each record to `output`
+    /// along with its line in the run file; ties go to the earlier run,
+    /// which keeps the sort stable.
+    fn merge<T, L, F>(&self, runs: Range<usize>, is_less: &L, output: &mut F) -> io::Result<()>
+    where
+        T: DeserializeOwned,
+        L: Fn(&T, &T) -> bool,
+        F: FnMut(T, &str) -> io::Result<()>,
+    {
+        let mut readers = Vec::with_capacity(runs.len());
+        for run in &self.runs[runs] {
+            let file = File::open(self.dir.path().join(&run.name))?;
+            readers.push((BufReader::new(file), String::new()));
+        }
+
+        let mut heap = BinaryHeap::with_comparator(2, |a: &(T, usize), b: &(T, usize)| {
+            if is_less(&a.0, &b.0) {
+                Ordering::Less
+            } else if is_less(&b.0, &a.0) {
+                Ordering::Greater
+            } else {
+                a.1.cmp(&b.1)
+            }
+        });
+        for (i, &mut (ref mut reader, ref mut line)) in readers.iter_mut().enumerate() {
+            if let Some(record) = next_record(reader, line)? {
+                heap.push((record, i));
+            }
+        }
+        while let Some((record, i)) = heap.pop() {
+            let (ref mut reader, ref mut line) = readers[i];
+            output(record, line)?;
+            if let Some(next) = next_record(reader, line)? {
+                heap.push((next, i));
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the runs of a completed sort.
+    fn finish(&mut self) -> io::Result<()> {
+        for run in self.runs.drain(..) {
+            fs::remove_file(self.dir.path().join(&run.name))?;
+        }
+        let manifest = self.dir.path().join(MANIFEST);
+        if manifest.exists() {
+            fs::remove_file(manifest)?;
+        }
+        self.dir.keep_on_drop(false);
+        Ok(())
+    }
+}
+
+/// Records of a fixed length read from `input`.
+struct FixedRecords<R> {
+    input: R,
+    record_len: usize,
+}
+
+impl<R: Read> Iterator for FixedRecords<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<io::Result<Vec<u8>>> {
+        let mut record = vec![0; self.record_len];
+        let mut filled = 0;
+        while filled < self.record_len {
+            match self.input.read(&mut record[filled..]) {
+                Ok(0) if filled == 0 => return None,
+                Ok(0) => return Some(Err(invalid_data("truncated record"))),
+                Ok(n) => filled += n,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        Some(Ok(record))
+    }
+}
+
+/// Read the next line of a run into `line` and decode its record.
+fn next_record<T, B>(reader: &mut B, line: &mut String) -> io::Result<Option<T>>
+where
+    T: DeserializeOwned,
+    B: BufRead,
+{
+    line.clear();
+    if reader.read_line(line)? == 0 {
+        return Ok(None);
+    }
+    serde_json::from_str(line).map(Some).map_err(invalid_data)
+}
+
+fn invalid_data<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
@@ -0,0 +1,68 @@
+use std::cmp::{Ordering, PartialEq, PartialOrd};
+
+/// In-place heap sort: O(n log n) in the worst case and O(1) extra space,
+/// but not stable.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::heap::heap_sort;
+///
+/// let mut source = vec![3, 2, 5, 1, 4];
+/// heap_sort(&mut source);
+/// assert_eq!(source, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn heap_sort<T: PartialEq + PartialOrd>(source: &mut [T]) {
+    heap_sort_by_less(source, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `heap_sort`, ordering elements with `compare`.
+pub fn heap_sort_by<T, F>(source: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heap_sort_by_less(source, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `heap_sort`, ordering elements by the key `key` extracts.
+pub fn heap_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    heap_sort_by_less(source, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+pub(crate) fn heap_sort_by_less<T, F>(source: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = source.len();
+    // build a max-heap bottom-up, then move the max to the end repeatedly
+    for i in (0..len / 2).rev() {
+        sift_down(source, i, len, is_less);
+    }
+    for end in (1..len).rev() {
+        source.swap(0, end);
+        sift_down(source, 0, end, is_less);
+    }
+}
+
+fn sift_down<T, F>(source: &mut [T], mut node: usize, end: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= end {
+            break;
+        }
+        if child + 1 < end && is_less(&source[child], &source[child + 1]) {
+            child += 1;
+        }
+        if !is_less(&source[node], &source[child]) {
+            break;
+        }
+        source.swap(node, child);
+        node = child;
+    }
+}
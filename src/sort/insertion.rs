@@ -0,0 +1,94 @@
+use std::cmp::{max, Ordering, PartialEq, PartialOrd};
+
+/// Slices not longer than this are finished by insertion sort in the
+/// quick sort based algorithms.
+pub(crate) const INSERTION_SORT_THRESHOLD: usize = 16;
+
+/// Stable insertion sort, O(n^2) but fast on short or nearly sorted slices.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::insertion::insertion_sort;
+///
+/// let mut source = vec![3, 2, 5, 1, 4];
+/// insertion_sort(&mut source);
+/// assert_eq!(source, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn insertion_sort<T: PartialEq + PartialOrd>(source: &mut [T]) {
+    insertion_sort_by_less(source, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `insertion_sort`, ordering elements with `compare`.
+pub fn insertion_sort_by<T, F>(source: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    insertion_sort_by_less(source, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `insertion_sort`, ordering elements by the key `key` extracts.
+pub fn insertion_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    insertion_sort_by_less(source, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+/// Stable insertion sort finding each insertion point by binary search, so
+/// it does O(n log n) comparisons (but still O(n^2) moves).
+pub fn binary_insertion_sort<T: PartialEq + PartialOrd>(source: &mut [T]) {
+    binary_insertion_sort_by_less(source, 0, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `binary_insertion_sort`, ordering elements with `compare`.
+pub fn binary_insertion_sort_by<T, F>(source: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    binary_insertion_sort_by_less(source, 0, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `binary_insertion_sort`, ordering elements by the key `key` extracts.
+pub fn binary_insertion_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    binary_insertion_sort_by_less(source, 0, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+pub(crate) fn insertion_sort_by_less<T, F>(source: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    for i in 1..source.len() {
+        let mut j = i;
+        while j > 0 && is_less(&source[j], &source[j - 1]) {
+            source.swap(j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+/// Binary insertion sort of `source`, whose first `sorted` elements are
+/// already sorted.
+pub(crate) fn binary_insertion_sort_by_less<T, F>(source: &mut [T], sorted: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    for i in max(sorted, 1)..source.len() {
+        // first position whose element is greater than source[i]
+        let mut lo = 0;
+        let mut hi = i;
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if is_less(&source[i], &source[mid]) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        source[lo..i + 1].rotate_right(1);
+    }
+}
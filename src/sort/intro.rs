@@ -0,0 +1,131 @@
+use std::cmp::{Ordering, PartialEq, PartialOrd};
+
+use sort::heap::heap_sort_by_less;
+use sort::insertion::{insertion_sort_by_less, INSERTION_SORT_THRESHOLD};
+use sort::quick::{choose_pivot, partition, PivotStrategy};
+
+/// Introspective sort: quick sort which switches to heap sort once the
+/// recursion gets deeper than `2 * log2(n)`, so the worst case stays
+/// O(n log n) even on adversarial input.
+///
+/// Partitions which are already sorted, or sorted in reverse, are detected
+/// and finished in O(n).
+///
+/// # Example
+/// ```
+/// use algorithm::sort::intro::intro_sort;
+///
+/// let mut source = vec![3, 2, 5, 1, 4];
+/// intro_sort(&mut source);
+/// assert_eq!(source, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn intro_sort<T: PartialEq + PartialOrd>(source: &mut [T]) {
+    let depth_limit = depth_limit(source.len());
+    sort_by_less(source, depth_limit, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `intro_sort`, ordering elements with `compare`.
+pub fn intro_sort_by<T, F>(source: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let depth_limit = depth_limit(source.len());
+    sort_by_less(source, depth_limit, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `intro_sort`, ordering elements by the key `key` extracts.
+pub fn intro_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    let depth_limit = depth_limit(source.len());
+    sort_by_less(source, depth_limit, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+/// `2 * log2(len)`, rounded up.
+fn depth_limit(len: usize) -> usize {
+    let mut depth_limit = 0;
+    let mut n = len;
+    while n > 0 {
+        depth_limit += 2;
+        n >>= 1;
+    }
+    depth_limit
+}
+
+enum Run {
+    Sorted,
+    Reversed,
+    Unsorted,
+}
+
+fn sort_by_less<T, F>(source: &mut [T], mut depth_limit: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut v = source;
+    loop {
+        if v.len() <= INSERTION_SORT_THRESHOLD {
+            insertion_sort_by_less(v, is_less);
+            return;
+        }
+
+        match detect_run(v, is_less) {
+            Run::Sorted => return,
+            Run::Reversed => {
+                v.reverse();
+                return;
+            }
+            Run::Unsorted => {}
+        }
+
+        if depth_limit == 0 {
+            heap_sort_by_less(v, is_less);
+            return;
+        }
+        depth_limit -= 1;
+
+        let p = choose_pivot(v, PivotStrategy::Ninther, is_less);
+        let (lt, gt) = partition(v, p, is_less);
+
+        let tmp = v;
+        let (left, rest) = tmp.split_at_mut(lt);
+        let right = &mut rest[gt - lt..];
+        if left.len() < right.len() {
+            sort_by_less(left, depth_limit, is_less);
+            v = right;
+        } else {
+            sort_by_less(right, depth_limit, is_less);
+            v = left;
+        }
+    }
+}
+
+/// Whether the slice is entirely ascending or descending.
+///
+/// Stops at the first pair breaking both orders, so on random input this
+/// costs only a couple of comparisons.
+fn detect_run<T, F>(source: &[T], is_less: &mut F) -> Run
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut ascending = true;
+    let mut descending = true;
+    for i in 1..source.len() {
+        if is_less(&source[i], &source[i - 1]) {
+            ascending = false;
+        } else if is_less(&source[i - 1], &source[i]) {
+            descending = false;
+        }
+        if !ascending && !descending {
+            return Run::Unsorted;
+        }
+    }
+
+    if ascending {
+        Run::Sorted
+    } else {
+        Run::Reversed
+    }
+}
@@ -0,0 +1,134 @@
+use std::cmp::{Ordering, PartialEq, PartialOrd};
+
+/// Stable top-down (recursive) merge sort.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::merge::merge_sort;
+///
+/// let mut source = vec![3, 2, 5, 1, 4];
+/// merge_sort(&mut source);
+/// assert_eq!(source, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn merge_sort<T: PartialEq + PartialOrd + Clone>(source: &mut [T]) {
+    top_down_by_less(source, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `merge_sort`, ordering elements with `compare`.
+pub fn merge_sort_by<T, F>(source: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    top_down_by_less(source, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `merge_sort`, ordering elements by the key `key` extracts.
+pub fn merge_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    T: Clone,
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    top_down_by_less(source, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+/// Stable bottom-up (iterative) merge sort, merging runs of width
+/// 1, 2, 4, ... until the whole slice is one run.
+pub fn merge_sort_bottom_up<T: PartialEq + PartialOrd + Clone>(source: &mut [T]) {
+    bottom_up_by_less(source, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `merge_sort_bottom_up`, ordering elements with `compare`.
+pub fn merge_sort_bottom_up_by<T, F>(source: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    bottom_up_by_less(source, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `merge_sort_bottom_up`, ordering elements by the key `key` extracts.
+pub fn merge_sort_bottom_up_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    T: Clone,
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    bottom_up_by_less(source, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+pub(crate) fn top_down_by_less<T, F>(source: &mut [T], is_less: &mut F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = source.len();
+    if len <= 1 {
+        return;
+    }
+
+    let mid = len / 2;
+    top_down_by_less(&mut source[..mid], is_less);
+    top_down_by_less(&mut source[mid..], is_less);
+    merge(source, mid, is_less);
+}
+
+fn bottom_up_by_less<T, F>(source: &mut [T], is_less: &mut F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = source.len();
+    let mut width = 1;
+    while width < len {
+        let mut lo = 0;
+        while lo + width < len {
+            let hi = if lo + 2 * width < len {
+                lo + 2 * width
+            } else {
+                len
+            };
+            merge(&mut source[lo..hi], width, is_less);
+            lo = hi;
+        }
+        width *= 2;
+    }
+}
+
+/// Merge the sorted runs `[0, mid)` and `[mid, len)` in place, using a
+/// buffer holding a copy of the left run.
+///
+/// On ties the element of the left run goes first, which is what makes the
+/// sort stable.
+pub(crate) fn merge<T, F>(source: &mut [T], mid: usize, is_less: &mut F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = source.len();
+    if mid == 0 || mid == len || !is_less(&source[mid], &source[mid - 1]) {
+        // already in order
+        return;
+    }
+
+    let left = source[..mid].to_vec();
+    let mut left = left.into_iter().peekable();
+    let mut j = mid;
+    let mut k = 0;
+    loop {
+        let take_right = match left.peek() {
+            None => break,
+            Some(l) => j < len && is_less(&source[j], l),
+        };
+        if take_right {
+            // source[k] was already copied into `left`, or moved forward
+            source.swap(k, j);
+            j += 1;
+        } else {
+            source[k] = left.next().unwrap();
+        }
+        k += 1;
+    }
+    // the rest of the right run is already in place
+}
@@ -1,2 +1,18 @@
 pub mod select;
-pub mod quick;
\ No newline at end of file
+pub mod quick;
+pub mod intro;
+pub mod merge;
+pub mod tim;
+pub mod heap;
+pub mod shell;
+pub mod insertion;
+pub mod radix;
+pub mod counting;
+pub mod bucket;
+pub mod cached;
+pub mod parallel;
+pub mod external;
+pub mod network;
+pub mod analysis;
+pub mod sorted;
+pub mod topological;
\ No newline at end of file
@@ -0,0 +1,203 @@
+use std::cmp::{PartialEq, PartialOrd};
+
+/// Comparator networks: `(i, j)` with `i < j` puts the smaller of
+/// `source[i]` and `source[j]` at `i`.
+pub type Network = [(usize, usize)];
+
+/// Optimal network for 4 elements: 5 comparators, depth 3.
+pub const SORT4: [(usize, usize); 5] = [(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)];
+
+/// Optimal network for 8 elements: 19 comparators, depth 6.
+#[rustfmt::skip]
+pub const SORT8: [(usize, usize); 19] = [
+    (0, 2), (1, 3), (4, 6), (5, 7),
+    (0, 4), (1, 5), (2, 6), (3, 7),
+    (0, 1), (2, 3), (4, 5), (6, 7),
+    (2, 4), (3, 5),
+    (1, 4), (3, 6),
+    (1, 2), (3, 4), (5, 6),
+];
+
+/// Best known network for 16 elements: 60 comparators, depth 10.
+#[rustfmt::skip]
+pub const SORT16: [(usize, usize); 60] = [
+    (0, 13), (1, 12), (2, 15), (3, 14), (4, 8), (5, 6), (7, 11), (9, 10),
+    (0, 5), (1, 7), (2, 9), (3, 4), (6, 13), (8, 14), (10, 15), (11, 12),
+    (0, 1), (2, 3), (4, 5), (6, 8), (7, 9), (10, 11), (12, 13), (14, 15),
+    (0, 2), (1, 3), (4, 10), (5, 11), (6, 7), (8, 9), (12, 14), (13, 15),
+    (1, 2), (3, 12), (4, 6), (5, 7), (8, 10), (9, 11), (13, 14),
+    (1, 4), (2, 6), (5, 8), (7, 10), (9, 13), (11, 14),
+    (2, 4), (3, 6), (9, 12), (11, 13),
+    (3, 5), (6, 8), (7, 9), (10, 12),
+    (3, 4), (5, 6), (7, 8), (9, 10), (11, 12),
+    (6, 7), (8, 9),
+];
+
+/// Longest slice `small_sort_by_less` handles.
+pub(crate) const MAX_NETWORK_LEN: usize = 16;
+
+/// Sort 4 elements with `SORT4`.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::network::sort4;
+///
+/// let mut source = [3, 1, 4, 2];
+/// sort4(&mut source);
+/// assert_eq!(source, [1, 2, 3, 4]);
+/// ```
+pub fn sort4<T: PartialEq + PartialOrd>(source: &mut [T; 4]) {
+    apply_network(source, &SORT4);
+}
+
+/// Sort 8 elements with `SORT8`.
+pub fn sort8<T: PartialEq + PartialOrd>(source: &mut [T; 8]) {
+    apply_network(source, &SORT8);
+}
+
+/// Sort 16 elements with `SORT16`.
+pub fn sort16<T: PartialEq + PartialOrd>(source: &mut [T; 16]) {
+    apply_network(source, &SORT16);
+}
+
+/// Run the comparators of `network` over `source` in order.
+///
+/// # Panics
+/// If a comparator refers to an index out of `source`.
+pub fn apply_network<T: PartialEq + PartialOrd>(source: &mut [T], network: &Network) {
+    apply_by_less(source, network, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `apply_network`, for `Copy` elements: each comparator reads both
+/// elements and writes back their minimum and maximum, selected by the
+/// comparison, instead of branching to a swap. The compiler turns such
+/// selects into conditional moves for register-sized types, so a network
+/// runs without data-dependent branches.
+///
+/// # Panics
+/// If a comparator refers to an index out of `source`.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::network::{apply_network_branchless, SORT8};
+///
+/// let mut source = [0.5, -1.0, 3.0, 2.5, 0.0, 7.0, -2.0, 1.0];
+/// apply_network_branchless(&mut source, &SORT8);
+/// assert_eq!(source, [-2.0, -1.0, 0.0, 0.5, 1.0, 2.5, 3.0, 7.0]);
+/// ```
+pub fn apply_network_branchless<T: Copy + PartialOrd>(source: &mut [T], network: &Network) {
+    for &(i, j) in network {
+        min_max(source, i, j, &mut |a: &T, b: &T| a < b);
+    }
+}
+
+/// Batcher's odd-even merge sort network for `len` elements, with
+/// O(n log^2 n) comparators.
+///
+/// Built for the next power of two, leaving out the comparators which
+/// would touch the padding (which sorts after every element and so would
+/// never be swapped).
+///
+/// # Example
+/// ```
+/// use algorithm::sort::network::{apply_network, batcher_network};
+///
+/// let network = batcher_network(4);
+/// assert_eq!(network, vec![(0, 1), (2, 3), (0, 2), (1, 3), (1, 2)]);
+///
+/// let mut source = vec![5, 2, 6, 1, 3];
+/// apply_network(&mut source, &batcher_network(5));
+/// assert_eq!(source, vec![1, 2, 3, 5, 6]);
+/// ```
+pub fn batcher_network(len: usize) -> Vec<(usize, usize)> {
+    let mut network = Vec::new();
+    // p: size of the sorted blocks being merged, k: comparator distance
+    let mut p = 1;
+    while p < len {
+        let mut k = p;
+        while k >= 1 {
+            let mut j = k % p;
+            while j + k < len {
+                for i in 0..k {
+                    let (a, b) = (i + j, i + j + k);
+                    if b >= len {
+                        break;
+                    }
+                    // only within the same pair of merged blocks
+                    if a / (2 * p) == b / (2 * p) {
+                        network.push((a, b));
+                    }
+                }
+                j += 2 * k;
+            }
+            k /= 2;
+        }
+        p *= 2;
+    }
+    network
+}
+
+/// Sort a slice of at most `MAX_NETWORK_LEN` elements with the smallest of
+/// `SORT4`, `SORT8` and `SORT16` that fits, treating the missing elements
+/// as larger than any other.
+pub(crate) fn small_sort_by_less<T, F>(source: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = source.len();
+    debug_assert!(len <= MAX_NETWORK_LEN);
+    let network: &Network = if len <= 4 {
+        &SORT4
+    } else if len <= 8 {
+        &SORT8
+    } else {
+        &SORT16
+    };
+    for &(i, j) in network {
+        if j < len {
+            compare_exchange(source, i, j, is_less);
+        }
+    }
+}
+
+fn apply_by_less<T, F>(source: &mut [T], network: &Network, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    for &(i, j) in network {
+        compare_exchange(source, i, j, is_less);
+    }
+}
+
+/// Put the smaller of `source[i]` and `source[j]` at `i`.
+///
+/// This is a plain conditional swap, so it still branches on the data,
+/// see `min_max` for the branchless form. The comparators of a network
+/// are fixed in advance, which is what makes a network cheap on tiny
+/// slices, not the absence of branches.
+#[inline]
+fn compare_exchange<T, F>(source: &mut [T], i: usize, j: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    if is_less(&source[j], &source[i]) {
+        source.swap(i, j);
+    }
+}
+
+/// Put the smaller of `source[i]` and `source[j]` at `i`, by writing both
+/// back selected by the comparison rather than swapping conditionally.
+///
+/// The pair is indexed with the comparison result: an `if` on it is often
+/// compiled back into a branch, the indexing into conditional moves.
+#[inline]
+fn min_max<T, F>(source: &mut [T], i: usize, j: usize, is_less: &mut F)
+where
+    T: Copy,
+    F: FnMut(&T, &T) -> bool,
+{
+    let pair = [source[i], source[j]];
+    let swap = is_less(&pair[1], &pair[0]);
+    source[i] = pair[swap as usize];
+    source[j] = pair[!swap as usize];
+}
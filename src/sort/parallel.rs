@@ -0,0 +1,357 @@
+use std::cmp::{max, min, Ordering, PartialEq, PartialOrd};
+
+use rayon::{self, ThreadPool, ThreadPoolBuilder};
+
+use sort::merge;
+use sort::quick::{self, choose_pivot, PivotStrategy};
+
+/// Slices not longer than this are sorted sequentially by default.
+pub const DEFAULT_CUTOFF: usize = 4096;
+
+/// Parallel merge sort and quick sort on a dedicated thread pool.
+///
+/// Both split the slice in halves (or partitions) sorted by fork-join on
+/// the pool, and fall back to the sequential `merge_sort` / `quick_sort`
+/// once a piece is not longer than `cutoff`. The merge sort is stable, so
+/// its result is exactly the one of `merge_sort`; the quick sort, like
+/// `quick_sort`, may order equal elements differently.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::parallel::ParallelSort;
+///
+/// let sorter = ParallelSort::with_cutoff(4, 2);
+/// let mut source = vec![3, 2, 5, 1, 4];
+/// sorter.quick_sort(&mut source);
+/// assert_eq!(source, vec![1, 2, 3, 4, 5]);
+/// ```
+pub struct ParallelSort {
+    pool: ThreadPool,
+    cutoff: usize,
+}
+
+impl ParallelSort {
+    /// Pool of `threads` threads (one per CPU when 0), with `DEFAULT_CUTOFF`.
+    ///
+    /// # Panics
+    /// If the threads cannot be spawned.
+    pub fn new(threads: usize) -> Self {
+        ParallelSort::with_cutoff(threads, DEFAULT_CUTOFF)
+    }
+
+    /// Pool of `threads` threads (one per CPU when 0), sorting slices not
+    /// longer than `cutoff` sequentially.
+    ///
+    /// # Panics
+    /// If the threads cannot be spawned.
+    pub fn with_cutoff(threads: usize, cutoff: usize) -> Self {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("failed to build sort thread pool");
+        ParallelSort {
+            pool,
+            cutoff,
+        }
+    }
+
+    pub fn threads(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+
+    pub fn cutoff(&self) -> usize {
+        self.cutoff
+    }
+
+    /// Stable parallel merge sort; halves are sorted in parallel and then
+    /// merged in parallel by splitting both runs around a median.
+    pub fn merge_sort<T>(&self, source: &mut [T])
+    where
+        T: PartialEq + PartialOrd + Clone + Send + Sync,
+    {
+        let cutoff = self.cutoff;
+        self.pool.install(|| merge_sort_by_less(source, cutoff, &|a: &T, b: &T| a < b));
+    }
+
+    /// Like `merge_sort`, ordering elements with `compare`.
+    pub fn merge_sort_by<T, F>(&self, source: &mut [T], compare: F)
+    where
+        T: Clone + Send + Sync,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let cutoff = self.cutoff;
+        let is_less = |a: &T, b: &T| compare(a, b) == Ordering::Less;
+        self.pool.install(|| merge_sort_by_less(source, cutoff, &is_less));
+    }
+
+    /// Like `merge_sort`, ordering elements by the key `key` extracts.
+    pub fn merge_sort_by_key<T, K, F>(&self, source: &mut [T], key: F)
+    where
+        T: Clone + Send + Sync,
+        K: PartialOrd,
+        F: Fn(&T) -> K + Sync,
+    {
+        let cutoff = self.cutoff;
+        let is_less = |a: &T, b: &T| key(a) < key(b);
+        self.pool.install(|| merge_sort_by_less(source, cutoff, &is_less));
+    }
+
+    /// Parallel quick sort: each slice is partitioned in place around its
+    /// pivot, its halves in parallel before the misplaced elements are
+    /// swapped across, and both partitions are sorted in parallel.
+    pub fn quick_sort<T>(&self, source: &mut [T])
+    where
+        T: PartialEq + PartialOrd + Send + Sync,
+    {
+        let cutoff = self.cutoff;
+        self.pool.install(|| quick_sort_by_less(source, cutoff, &|a: &T, b: &T| a < b));
+    }
+
+    /// Like `quick_sort`, ordering elements with `compare`.
+    pub fn quick_sort_by<T, F>(&self, source: &mut [T], compare: F)
+    where
+        T: Send + Sync,
+        F: Fn(&T, &T) -> Ordering + Sync,
+    {
+        let cutoff = self.cutoff;
+        let is_less = |a: &T, b: &T| compare(a, b) == Ordering::Less;
+        self.pool.install(|| quick_sort_by_less(source, cutoff, &is_less));
+    }
+
+    /// Like `quick_sort`, ordering elements by the key `key` extracts.
+    pub fn quick_sort_by_key<T, K, F>(&self, source: &mut [T], key: F)
+    where
+        T: Send + Sync,
+        K: PartialOrd,
+        F: Fn(&T) -> K + Sync,
+    {
+        let cutoff = self.cutoff;
+        let is_less = |a: &T, b: &T| key(a) < key(b);
+        self.pool.install(|| quick_sort_by_less(source, cutoff, &is_less));
+    }
+}
+
+/// Parallel merge sort on the global thread pool with `DEFAULT_CUTOFF`.
+pub fn par_merge_sort<T>(source: &mut [T])
+where
+    T: PartialEq + PartialOrd + Clone + Send + Sync,
+{
+    merge_sort_by_less(source, DEFAULT_CUTOFF, &|a: &T, b: &T| a < b);
+}
+
+/// Parallel quick sort on the global thread pool with `DEFAULT_CUTOFF`.
+pub fn par_quick_sort<T>(source: &mut [T])
+where
+    T: PartialEq + PartialOrd + Send + Sync,
+{
+    quick_sort_by_less(source, DEFAULT_CUTOFF, &|a: &T, b: &T| a < b);
+}
+
+fn merge_sort_by_less<T, F>(source: &mut [T], cutoff: usize, is_less: &F)
+where
+    T: Clone + Send + Sync,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    let len = source.len();
+    if len <= cutoff || len < 2 {
+        merge::top_down_by_less(source, &mut |a: &T, b: &T| is_less(a, b));
+        return;
+    }
+
+    let mid = len / 2;
+    {
+        let (left, right) = source.split_at_mut(mid);
+        rayon::join(
+            || merge_sort_by_less(left, cutoff, is_less),
+            || merge_sort_by_less(right, cutoff, is_less),
+        );
+    }
+    if !is_less(&source[mid], &source[mid - 1]) {
+        // already in order
+        return;
+    }
+
+    let mut scratch = source.to_vec();
+    sort_with_scratch(source, &mut scratch, cutoff, is_less);
+}
+
+/// Sort `source`, holding the same elements as `scratch`, which is left in
+/// any order.
+///
+/// The halves are sorted into `scratch`, with the halves of `source` as
+/// their scratch, and merged back: the two buffers swap roles at every
+/// level, so nothing is allocated below the top.
+fn sort_with_scratch<T, F>(source: &mut [T], scratch: &mut [T], cutoff: usize, is_less: &F)
+where
+    T: Clone + Send + Sync,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    let len = source.len();
+    if len <= cutoff || len < 2 {
+        merge::top_down_by_less(source, &mut |a: &T, b: &T| is_less(a, b));
+        return;
+    }
+
+    let mid = len / 2;
+    {
+        let (source_lo, source_hi) = source.split_at_mut(mid);
+        let (scratch_lo, scratch_hi) = scratch.split_at_mut(mid);
+        rayon::join(
+            || sort_with_scratch(scratch_lo, source_lo, cutoff, is_less),
+            || sort_with_scratch(scratch_hi, source_hi, cutoff, is_less),
+        );
+    }
+    let (left, right) = scratch.split_at(mid);
+    if !is_less(&right[0], &left[mid - 1]) {
+        // already in order
+        source.clone_from_slice(scratch);
+        return;
+    }
+    merge_into(left, right, source, cutoff, is_less);
+}
+
+/// Stable merge of the sorted `left` and `right` into `dest`.
+///
+/// The longer run is split at its middle element and the other one at the
+/// matching position, which gives two independent merges of about half the
+/// size each.
+fn merge_into<T, F>(left: &[T], right: &[T], dest: &mut [T], cutoff: usize, is_less: &F)
+where
+    T: Clone + Send + Sync,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    // below three elements the split could leave one side with everything
+    if left.len() + right.len() <= max(cutoff, 2) || left.is_empty() || right.is_empty() {
+        let (mut i, mut j) = (0, 0);
+        for slot in dest.iter_mut() {
+            // take from the right only when strictly less, for stability
+            if j < right.len() && (i == left.len() || is_less(&right[j], &left[i])) {
+                *slot = right[j].clone();
+                j += 1;
+            } else {
+                *slot = left[i].clone();
+                i += 1;
+            }
+        }
+        return;
+    }
+
+    let (i, j) = if left.len() >= right.len() {
+        // right elements less than left[i] go first
+        let i = left.len() / 2;
+        (i, partition_point(right, |x| is_less(x, &left[i])))
+    } else {
+        // left elements not greater than right[j] go first
+        let j = right.len() / 2;
+        (partition_point(left, |x| !is_less(&right[j], x)), j)
+    };
+
+    let (dest_lo, dest_hi) = dest.split_at_mut(i + j);
+    rayon::join(
+        || merge_into(&left[..i], &right[..j], dest_lo, cutoff, is_less),
+        || merge_into(&left[i..], &right[j..], dest_hi, cutoff, is_less),
+    );
+}
+
+/// Number of leading elements satisfying `pred`, which must hold for a
+/// prefix of the slice only.
+fn partition_point<T, P: Fn(&T) -> bool>(source: &[T], pred: P) -> usize {
+    let (mut lo, mut hi) = (0, source.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(&source[mid]) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+fn quick_sort_by_less<T, F>(source: &mut [T], cutoff: usize, is_less: &F)
+where
+    T: Send + Sync,
+    F: Fn(&T, &T) -> bool + Sync,
+{
+    let len = source.len();
+    if len <= cutoff || len < 2 {
+        quick::sort_by_less(source, PivotStrategy::Ninther, &mut |a: &T, b: &T| is_less(a, b));
+        return;
+    }
+
+    let p = choose_pivot(source, PivotStrategy::Ninther, &mut |a: &T, b: &T| is_less(a, b));
+    source.swap(0, p);
+    let (lt, eq) = {
+        let (pivot, rest) = source.split_first_mut().unwrap();
+        let pivot = &*pivot;
+        let lt = partition(rest, cutoff, &|x: &T| is_less(x, pivot));
+        if lt > 0 {
+            (lt, 0)
+        } else {
+            // the pivot is the smallest element: split off its equals, or
+            // many of them would only ever peel off one per level
+            (0, partition(rest, cutoff, &|x: &T| !is_less(pivot, x)))
+        }
+    };
+    source.swap(0, lt);
+
+    let (left, rest) = source.split_at_mut(lt);
+    let right = &mut rest[1 + eq..];
+    rayon::join(
+        || quick_sort_by_less(left, cutoff, is_less),
+        || quick_sort_by_less(right, cutoff, is_less),
+    );
+}
+
+/// Move the elements satisfying `pred` to the front of `source` and return
+/// how many there are.
+///
+/// Both halves are partitioned in parallel, which leaves the elements to
+/// move between them, then the smaller of the two misplaced blocks is
+/// swapped with the far end of the other one, in parallel as well.
+fn partition<T, P>(source: &mut [T], cutoff: usize, pred: &P) -> usize
+where
+    T: Send,
+    P: Fn(&T) -> bool + Sync,
+{
+    let len = source.len();
+    if len <= max(cutoff, 1) {
+        let mut k = 0;
+        for i in 0..len {
+            if pred(&source[i]) {
+                source.swap(i, k);
+                k += 1;
+            }
+        }
+        return k;
+    }
+
+    let mid = len / 2;
+    let (a, b) = {
+        let (lo, hi) = source.split_at_mut(mid);
+        rayon::join(|| partition(lo, cutoff, pred), || partition(hi, cutoff, pred))
+    };
+    // source is now [lo yes | lo no | hi yes | hi no]
+    let misplaced = &mut source[a..mid + b];
+    let moved = min(mid - a, b);
+    let far = misplaced.len() - moved;
+    let (near, far) = misplaced.split_at_mut(far);
+    swap_blocks(&mut near[..moved], far, cutoff);
+    a + b
+}
+
+/// Swap the elements of `left` and `right`, which have the same length.
+fn swap_blocks<T: Send>(left: &mut [T], right: &mut [T], cutoff: usize) {
+    if left.len() <= max(cutoff, 1) {
+        left.swap_with_slice(right);
+        return;
+    }
+
+    let mid = left.len() / 2;
+    let (left_lo, left_hi) = left.split_at_mut(mid);
+    let (right_lo, right_hi) = right.split_at_mut(mid);
+    rayon::join(
+        || swap_blocks(left_lo, right_lo, cutoff),
+        || swap_blocks(left_hi, right_hi, cutoff),
+    );
+}
@@ -1,35 +1,158 @@
-use std::cmp::{PartialEq, PartialOrd};
-// use std::clone::Clone;
+use std::cmp::{Ordering, PartialEq, PartialOrd};
 
-pub fn quick_sort<T: PartialEq + PartialOrd>(source: &mut Vec<T>) {
-    if (*source).len() <= 1 {
-        return
-    }
+use rand::{thread_rng, Rng};
+
+use sort::network::{small_sort_by_less, MAX_NETWORK_LEN};
+
+/// Partitions longer than this use Tukey's ninther instead of a plain
+/// median of three when `PivotStrategy::Ninther` is selected.
+const NINTHER_THRESHOLD: usize = 40;
+
+/// How `quick_sort_with` picks the pivot of a partition.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PivotStrategy {
+    /// Median of the first, middle and last elements.
+    MedianOfThree,
+    /// Median of three medians of three (median of three on small partitions).
+    Ninther,
+    /// Uniformly random element.
+    Random,
+}
+
+/// Sort the slice in place with `PivotStrategy::Ninther`.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::quick::quick_sort;
+///
+/// let mut source = vec![3, 2, 5, 1, 4];
+/// quick_sort(&mut source[1..4]);
+/// assert_eq!(source, vec![3, 1, 2, 5, 4]);
+/// ```
+pub fn quick_sort<T: PartialEq + PartialOrd>(source: &mut [T]) {
+    quick_sort_with(source, PivotStrategy::Ninther);
+}
 
-    let base = (*source).pop().unwrap();
+/// Sort the slice in place, picking pivots with `pivot`.
+///
+/// Partitioning is three-way (less / equal / greater), so slices with many
+/// duplicated values stay O(n log n).
+pub fn quick_sort_with<T: PartialEq + PartialOrd>(source: &mut [T], pivot: PivotStrategy) {
+    sort_by_less(source, pivot, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `quick_sort`, ordering elements with `compare`.
+pub fn quick_sort_by<T, F>(source: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    sort_by_less(source, PivotStrategy::Ninther, &mut |a: &T, b: &T| {
+        compare(a, b) == Ordering::Less
+    });
+}
 
-    let mut less = Vec::new();
-    let mut greater = Vec::new();
+/// Like `quick_sort`, ordering elements by the key `key` extracts.
+pub fn quick_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    sort_by_less(source, PivotStrategy::Ninther, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+pub(crate) fn sort_by_less<T, F>(source: &mut [T], pivot: PivotStrategy, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut v = source;
     loop {
-        if (*source).len() <= 0 {
-            break;
+        if v.len() <= MAX_NETWORK_LEN {
+            small_sort_by_less(v, is_less);
+            return;
         }
 
-        let v = (*source).pop().unwrap();
-        if v < base {
-            less.push(v);
+        let p = choose_pivot(v, pivot, is_less);
+        let (lt, gt) = partition(v, p, is_less);
+
+        // recurse into the smaller side and loop on the larger one,
+        // so the stack depth stays O(log n)
+        let tmp = v;
+        let (left, rest) = tmp.split_at_mut(lt);
+        let right = &mut rest[gt - lt..];
+        if left.len() < right.len() {
+            sort_by_less(left, pivot, is_less);
+            v = right;
+        } else {
+            sort_by_less(right, pivot, is_less);
+            v = left;
+        }
+    }
+}
+
+/// Dijkstra's three-way partition around `source[p]`.
+///
+/// Returns `(lt, gt)` such that `[0, lt)` is less than the pivot,
+/// `[lt, gt)` is equal to it and `[gt, len)` is greater.
+pub(crate) fn partition<T, F>(source: &mut [T], p: usize, is_less: &mut F) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    source.swap(0, p);
+    // source[lt] always holds a value equal to the pivot
+    let mut lt = 0;
+    let mut i = 1;
+    let mut gt = source.len();
+    while i < gt {
+        if is_less(&source[i], &source[lt]) {
+            source.swap(lt, i);
+            lt += 1;
+            i += 1;
+        } else if is_less(&source[lt], &source[i]) {
+            gt -= 1;
+            source.swap(i, gt);
+        } else {
+            i += 1;
+        }
+    }
+    (lt, gt)
+}
+
+pub(crate) fn choose_pivot<T, F>(source: &[T], pivot: PivotStrategy, is_less: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = source.len();
+    let mid = len / 2;
+    match pivot {
+        PivotStrategy::Random => thread_rng().gen_range(0, len),
+        PivotStrategy::Ninther if len > NINTHER_THRESHOLD => {
+            let step = len / 8;
+            let a = median_of_three(source, 0, step, 2 * step, is_less);
+            let b = median_of_three(source, mid - step, mid, mid + step, is_less);
+            let c = median_of_three(source, len - 1 - 2 * step, len - 1 - step, len - 1, is_less);
+            median_of_three(source, a, b, c, is_less)
+        }
+        _ => median_of_three(source, 0, mid, len - 1, is_less),
+    }
+}
+
+fn median_of_three<T, F>(source: &[T], a: usize, b: usize, c: usize, is_less: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    if is_less(&source[a], &source[b]) {
+        if is_less(&source[b], &source[c]) {
+            b
+        } else if is_less(&source[a], &source[c]) {
+            c
         } else {
-            greater.push(v);
+            a
         }
+    } else if is_less(&source[a], &source[c]) {
+        a
+    } else if is_less(&source[b], &source[c]) {
+        c
+    } else {
+        b
     }
-    
-    let mut sort_array = Vec::new();
-    quick_sort(&mut less);
-    quick_sort(&mut greater);
-    sort_array.append(&mut less);
-    sort_array.push(base);
-    sort_array.append(&mut greater);
-
-    (*source).clear();
-    source.append(&mut sort_array);
 }
@@ -0,0 +1,337 @@
+use std::cmp::Ordering;
+use std::mem;
+
+/// Digits of one radix pass: 256 byte values plus one for "past the end of
+/// the key", which sorts before every byte so that prefixes come first.
+const BUCKETS: usize = 257;
+
+/// Sub-slices not longer than this are finished by insertion sort in
+/// `msd_radix_sort`.
+const MSD_INSERTION_THRESHOLD: usize = 16;
+
+/// Key which can be sorted byte by byte.
+///
+/// Radix sorts never compare two elements, so unlike the comparison sorts
+/// they have `_by_key` variants but no `_by(compare)` ones.
+///
+/// Comparing the bytes `key_byte(0), key_byte(1), ...` lexicographically
+/// (a shorter key before any key it is a prefix of) must give the order
+/// the key should be sorted in.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::radix::{radix_sort, RadixKey};
+///
+/// // sorted by version, newest first
+/// struct Release {
+///     version: (u8, u8),
+/// }
+///
+/// impl RadixKey for Release {
+///     fn key_len(&self) -> usize {
+///         2
+///     }
+///
+///     fn key_byte(&self, index: usize) -> u8 {
+///         let byte = if index == 0 { self.version.0 } else { self.version.1 };
+///         !byte
+///     }
+/// }
+///
+/// let mut source = vec![Release { version: (1, 2) }, Release { version: (2, 0) }];
+/// radix_sort(&mut source);
+/// assert_eq!(source[0].version, (2, 0));
+/// ```
+pub trait RadixKey {
+    /// Number of bytes in the key.
+    fn key_len(&self) -> usize;
+
+    /// Byte `index` of the key, most significant first (`index < key_len()`).
+    fn key_byte(&self, index: usize) -> u8;
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($t:ty),*) => {$(
+        impl RadixKey for $t {
+            fn key_len(&self) -> usize {
+                mem::size_of::<$t>()
+            }
+
+            fn key_byte(&self, index: usize) -> u8 {
+                (*self >> (8 * (mem::size_of::<$t>() - 1 - index))) as u8
+            }
+        }
+    )*}
+}
+
+// two's complement with the sign bit flipped orders like the unsigned type
+macro_rules! impl_radix_key_signed {
+    ($($t:ty => $u:ty),*) => {$(
+        impl RadixKey for $t {
+            fn key_len(&self) -> usize {
+                mem::size_of::<$t>()
+            }
+
+            fn key_byte(&self, index: usize) -> u8 {
+                let bits = (*self as $u) ^ (1 << (8 * mem::size_of::<$u>() - 1));
+                bits.key_byte(index)
+            }
+        }
+    )*}
+}
+
+// IEEE 754: flip every bit of negative numbers and only the sign bit of the
+// others, so -inf < ... < -0.0 < 0.0 < ... < inf, with NaNs at both ends
+macro_rules! impl_radix_key_float {
+    ($($t:ty => $u:ty),*) => {$(
+        impl RadixKey for $t {
+            fn key_len(&self) -> usize {
+                mem::size_of::<$t>()
+            }
+
+            fn key_byte(&self, index: usize) -> u8 {
+                let bits = self.to_bits();
+                let sign = 1 << (8 * mem::size_of::<$u>() - 1);
+                let bits = if bits & sign != 0 { !bits } else { bits ^ sign };
+                bits.key_byte(index)
+            }
+        }
+    )*}
+}
+
+impl_radix_key_unsigned!(u8, u16, u32, u64, usize);
+impl_radix_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+impl_radix_key_float!(f32 => u32, f64 => u64);
+
+impl RadixKey for [u8] {
+    fn key_len(&self) -> usize {
+        self.len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self[index]
+    }
+}
+
+impl RadixKey for Vec<u8> {
+    fn key_len(&self) -> usize {
+        self.len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self[index]
+    }
+}
+
+impl RadixKey for str {
+    fn key_len(&self) -> usize {
+        self.len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self.as_bytes()[index]
+    }
+}
+
+impl RadixKey for String {
+    fn key_len(&self) -> usize {
+        self.len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self.as_bytes()[index]
+    }
+}
+
+impl<T: RadixKey + ?Sized> RadixKey for &T {
+    fn key_len(&self) -> usize {
+        (**self).key_len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        (**self).key_byte(index)
+    }
+}
+
+/// Stable least significant digit radix sort, one counting pass per key
+/// byte: O(w * n) for keys of at most `w` bytes.
+///
+/// Keys of different lengths (byte strings) are sorted with the shorter
+/// ones padded by a digit smaller than any byte.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::radix::radix_sort;
+///
+/// let mut source = vec![3, -2, 500, -70000, 4];
+/// radix_sort(&mut source);
+/// assert_eq!(source, vec![-70000, -2, 3, 4, 500]);
+/// ```
+pub fn radix_sort<T: RadixKey>(source: &mut [T]) {
+    let order = lsd_order(source);
+    apply_permutation(source, &order);
+}
+
+/// Like `radix_sort`, ordering elements by the key `key` extracts. Each
+/// key is extracted once.
+pub fn radix_sort_by_key<T, K, F>(source: &mut [T], key: F)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<K> = source.iter().map(key).collect();
+    let order = lsd_order(&keys);
+    apply_permutation(source, &order);
+}
+
+/// Stable most significant digit radix sort, which only looks at the bytes
+/// needed to tell the keys apart, so it suits long keys with short common
+/// prefixes (e.g. strings).
+///
+/// # Example
+/// ```
+/// use algorithm::sort::radix::msd_radix_sort;
+///
+/// let mut source = vec!["banana", "apple", "app", "cherry"];
+/// msd_radix_sort(&mut source);
+/// assert_eq!(source, vec!["app", "apple", "banana", "cherry"]);
+/// ```
+pub fn msd_radix_sort<T: RadixKey>(source: &mut [T]) {
+    let order = msd_order(source);
+    apply_permutation(source, &order);
+}
+
+/// Like `msd_radix_sort`, ordering elements by the key `key` extracts.
+/// Each key is extracted once.
+pub fn msd_radix_sort_by_key<T, K, F>(source: &mut [T], key: F)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    let keys: Vec<K> = source.iter().map(key).collect();
+    let order = msd_order(&keys);
+    apply_permutation(source, &order);
+}
+
+/// Indices of `keys` in stably sorted order, by LSD radix sort.
+pub(crate) fn lsd_order<K: RadixKey>(keys: &[K]) -> Vec<usize> {
+    let len = keys.len();
+    let width = keys.iter().map(|k| k.key_len()).max().unwrap_or(0);
+    let mut order: Vec<usize> = (0..len).collect();
+    let mut next = vec![0; len];
+    for digit in (0..width).rev() {
+        let mut counts = [0usize; BUCKETS + 1];
+        for &i in &order {
+            counts[bucket(&keys[i], digit) + 1] += 1;
+        }
+        if counts.contains(&len) {
+            // every key has the same digit here
+            continue;
+        }
+
+        for b in 0..BUCKETS {
+            counts[b + 1] += counts[b];
+        }
+        for &i in &order {
+            let b = bucket(&keys[i], digit);
+            next[counts[b]] = i;
+            counts[b] += 1;
+        }
+        mem::swap(&mut order, &mut next);
+    }
+    order
+}
+
+/// Indices of `keys` in stably sorted order, by MSD radix sort.
+pub(crate) fn msd_order<K: RadixKey>(keys: &[K]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    let mut buffer = vec![0; keys.len()];
+    msd(keys, &mut order, &mut buffer, 0);
+    order
+}
+
+/// Rearrange `source` so that `source[i]` becomes the old
+/// `source[order[i]]`, following the cycles of the permutation.
+pub(crate) fn apply_permutation<T>(source: &mut [T], order: &[usize]) {
+    let mut done = vec![false; source.len()];
+    for start in 0..source.len() {
+        if done[start] {
+            continue;
+        }
+        let mut i = start;
+        loop {
+            done[i] = true;
+            let next = order[i];
+            if next == start {
+                break;
+            }
+            source.swap(i, next);
+            i = next;
+        }
+    }
+}
+
+/// Digit of `key` at `index`, with 0 reserved for "past the end".
+fn bucket<K: RadixKey>(key: &K, index: usize) -> usize {
+    if index < key.key_len() {
+        key.key_byte(index) as usize + 1
+    } else {
+        0
+    }
+}
+
+fn msd<K: RadixKey>(keys: &[K], order: &mut [usize], buffer: &mut [usize], digit: usize) {
+    if order.len() <= MSD_INSERTION_THRESHOLD {
+        for i in 1..order.len() {
+            let mut j = i;
+            while j > 0
+                && compare_from(&keys[order[j]], &keys[order[j - 1]], digit) == Ordering::Less
+            {
+                order.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+        return;
+    }
+
+    let mut counts = [0usize; BUCKETS + 1];
+    for &i in order.iter() {
+        counts[bucket(&keys[i], digit) + 1] += 1;
+    }
+    for b in 0..BUCKETS {
+        counts[b + 1] += counts[b];
+    }
+
+    // counts[b] is the start of bucket b now, and its end after scattering
+    let starts = counts;
+    for &i in order.iter() {
+        let b = bucket(&keys[i], digit);
+        buffer[counts[b]] = i;
+        counts[b] += 1;
+    }
+    order.copy_from_slice(buffer);
+
+    // keys in bucket 0 have ended, so they are all equal
+    for b in 1..BUCKETS {
+        let (lo, hi) = (starts[b], counts[b]);
+        if hi - lo > 1 {
+            msd(keys, &mut order[lo..hi], &mut buffer[lo..hi], digit + 1);
+        }
+    }
+}
+
+/// Compare two keys byte by byte, starting at `digit`.
+fn compare_from<K: RadixKey>(a: &K, b: &K, digit: usize) -> Ordering {
+    let mut index = digit;
+    loop {
+        match (index < a.key_len(), index < b.key_len()) {
+            (false, false) => return Ordering::Equal,
+            (false, true) => return Ordering::Less,
+            (true, false) => return Ordering::Greater,
+            (true, true) => match a.key_byte(index).cmp(&b.key_byte(index)) {
+                Ordering::Equal => index += 1,
+                other => return other,
+            },
+        }
+    }
+}
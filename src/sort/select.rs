@@ -1,31 +1,254 @@
-use std::cmp::{PartialEq, PartialOrd};
+use std::cmp::{Ordering, PartialEq, PartialOrd};
 
-pub fn selection_sort<T: PartialEq + PartialOrd>(source: &mut Vec<T>) {
-    let mut sorted_array = Vec::new();
-    loop {
-        if (*source).len() <= 0 {
-            break;
-        }
+use heap::binary_heap::BinaryHeap;
+use sort::heap::heap_sort_by_less;
+use sort::insertion::{insertion_sort_by_less, INSERTION_SORT_THRESHOLD};
+use sort::quick::{choose_pivot, partition, PivotStrategy};
+
+/// Stable selection sort: O(n^2) comparisons, and O(n^2) moves as the
+/// smallest element left is rotated into place.
+pub fn selection_sort<T: PartialEq + PartialOrd>(source: &mut [T]) {
+    sort_by_less(source, &mut |a: &T, b: &T| a < b);
+}
 
-        let smallest = find_smallest(source);
-        println!("smallest:{}", smallest);
-        sorted_array.push((*source).remove(smallest));
+/// Like `selection_sort`, ordering elements with `compare`.
+pub fn selection_sort_by<T, F>(source: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    sort_by_less(source, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `selection_sort`, ordering elements by the key `key` extracts.
+pub fn selection_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    sort_by_less(source, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+/// Reorder the slice so that `source[k]` is the element which would be
+/// there if the slice were sorted, with nothing greater before it and
+/// nothing less after it. Quickselect, expected O(n).
+///
+/// # Panics
+/// If `k >= source.len()`.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::select::select_nth_unstable;
+///
+/// let mut source = vec![9, 1, 8, 2, 7, 3];
+/// assert_eq!(*select_nth_unstable(&mut source, 2), 3);
+/// assert!(source[..2].iter().all(|&x| x <= 3));
+/// assert!(source[3..].iter().all(|&x| x >= 3));
+/// ```
+pub fn select_nth_unstable<T: PartialEq + PartialOrd>(source: &mut [T], k: usize) -> &mut T {
+    select_by_less(source, k, &mut |a: &T, b: &T| a < b)
+}
+
+/// Like `select_nth_unstable`, ordering elements with `compare`.
+pub fn select_nth_unstable_by<T, F>(source: &mut [T], k: usize, mut compare: F) -> &mut T
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    select_by_less(source, k, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less)
+}
+
+/// Like `select_nth_unstable`, ordering elements by the key `key` extracts.
+pub fn select_nth_unstable_by_key<T, K, F>(source: &mut [T], k: usize, mut key: F) -> &mut T
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    select_by_less(source, k, &mut |a: &T, b: &T| key(a) < key(b))
+}
+
+/// Like `select_nth_unstable`, but picking pivots by the median of medians
+/// of five, which guarantees O(n) in the worst case (at a higher constant).
+pub fn select_nth_median_of_medians<T>(source: &mut [T], k: usize) -> &mut T
+where
+    T: PartialEq + PartialOrd,
+{
+    assert!(k < source.len(), "index {} out of range for length {}", k, source.len());
+    median_of_medians_by_less(source, k, &mut |a: &T, b: &T| a < b);
+    &mut source[k]
+}
+
+/// The `n` largest items of `iter`, largest first.
+///
+/// Only a heap of `n` items is kept, so `iter` may be an unbounded stream.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::select::top_k;
+///
+/// assert_eq!(top_k(vec![4, 9, 1, 7, 3], 3), vec![9, 7, 4]);
+/// ```
+pub fn top_k<T, I>(iter: I, n: usize) -> Vec<T>
+where
+    T: PartialOrd,
+    I: IntoIterator<Item = T>,
+{
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // min-heap of the largest items so far, its top is the one to drop next
+    let mut heap = BinaryHeap::with_comparator(2, |a: &T, b: &T| {
+        a.partial_cmp(b).unwrap_or(Ordering::Equal)
+    });
+    for item in iter {
+        if heap.len() < n {
+            heap.push(item);
+        } else if heap.peek().is_some_and(|min| *min < item) {
+            heap.pop();
+            heap.push(item);
+        }
     }
-    (*source).append(&mut sorted_array);
+
+    let mut result = heap.into_sorted_vec();
+    result.reverse();
+    result
+}
+
+/// Sort only the `k` smallest elements into `source[..k]`, leaving the
+/// rest in unspecified order. O(n + k log k).
+///
+/// # Example
+/// ```
+/// use algorithm::sort::select::partial_sort;
+///
+/// let mut source = vec![9, 1, 8, 2, 7, 3];
+/// partial_sort(&mut source, 3);
+/// assert_eq!(source[..3], [1, 2, 3]);
+/// ```
+pub fn partial_sort<T: PartialEq + PartialOrd>(source: &mut [T], k: usize) {
+    partial_sort_by_less(source, k, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `partial_sort`, ordering elements with `compare`.
+pub fn partial_sort_by<T, F>(source: &mut [T], k: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    partial_sort_by_less(source, k, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `partial_sort`, ordering elements by the key `key` extracts.
+pub fn partial_sort_by_key<T, K, F>(source: &mut [T], k: usize, mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    partial_sort_by_less(source, k, &mut |a: &T, b: &T| key(a) < key(b));
 }
 
 type Index = usize;
 
-fn find_smallest<T: PartialEq + PartialOrd>(source: &Vec<T>) -> Index {
-    let mut smallest = &(*source)[0];
-    let mut index:Index = 0;
+fn sort_by_less<T, F>(source: &mut [T], is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    for i in 0..source.len() {
+        let smallest = i + find_smallest(&source[i..], is_less);
+        // rotating rather than swapping keeps equal elements in order
+        source[i..=smallest].rotate_right(1);
+    }
+}
+
+fn find_smallest<T, F>(source: &[T], is_less: &mut F) -> Index
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut index: Index = 0;
 
-    for (i, v) in (*source).iter().enumerate() {
-        if *v < *smallest {
-            smallest = v;
+    for (i, v) in source.iter().enumerate() {
+        if is_less(v, &source[index]) {
             index = i as Index;
         }
     }
 
     index
 }
+
+fn select_by_less<'a, T, F>(source: &'a mut [T], k: usize, is_less: &mut F) -> &'a mut T
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    assert!(k < source.len(), "index {} out of range for length {}", k, source.len());
+    let mut v = &mut source[..];
+    let mut k = k;
+    loop {
+        if v.len() <= INSERTION_SORT_THRESHOLD {
+            insertion_sort_by_less(v, is_less);
+            return &mut v[k];
+        }
+
+        let p = choose_pivot(v, PivotStrategy::Ninther, is_less);
+        let (lt, gt) = partition(v, p, is_less);
+
+        // only the side holding k needs further work
+        let tmp = v;
+        if k < lt {
+            v = &mut tmp[..lt];
+        } else if k >= gt {
+            v = &mut tmp[gt..];
+            k -= gt;
+        } else {
+            return &mut tmp[k];
+        }
+    }
+}
+
+fn median_of_medians_by_less<T, F>(source: &mut [T], k: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut v = source;
+    let mut k = k;
+    loop {
+        let len = v.len();
+        if len <= 5 {
+            insertion_sort_by_less(v, is_less);
+            return;
+        }
+
+        // move the median of every group of five to the front; position
+        // `g` always lies in an already processed group
+        let groups = len.div_ceil(5);
+        for g in 0..groups {
+            let lo = 5 * g;
+            let hi = if lo + 5 < len { lo + 5 } else { len };
+            insertion_sort_by_less(&mut v[lo..hi], is_less);
+            v.swap(g, lo + (hi - lo - 1) / 2);
+        }
+        median_of_medians_by_less(&mut v[..groups], groups / 2, is_less);
+
+        let (lt, gt) = partition(v, groups / 2, is_less);
+        let tmp = v;
+        if k < lt {
+            v = &mut tmp[..lt];
+        } else if k >= gt {
+            v = &mut tmp[gt..];
+            k -= gt;
+        } else {
+            return;
+        }
+    }
+}
+
+fn partial_sort_by_less<T, F>(source: &mut [T], k: usize, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    if k == 0 {
+        return;
+    }
+    if k < source.len() {
+        select_by_less(source, k - 1, is_less);
+    }
+    let k = if k < source.len() { k } else { source.len() };
+    heap_sort_by_less(&mut source[..k], is_less);
+}
@@ -0,0 +1,118 @@
+use std::cmp::{Ordering, PartialEq, PartialOrd};
+
+/// Ciura's experimentally determined gaps, extended by a factor of 2.25.
+const CIURA_GAPS: [usize; 9] = [1, 4, 10, 23, 57, 132, 301, 701, 1750];
+
+/// Gap sequence used by `shell_sort_with`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GapSequence {
+    /// Shell's original `n/2, n/4, ..., 1`, O(n^2) in the worst case.
+    Shell,
+    /// Knuth's `(3^k - 1) / 2`: 1, 4, 13, 40, ...
+    Knuth,
+    /// Ciura's 1, 4, 10, 23, 57, 132, 301, 701, 1750, then `* 2.25`.
+    Ciura,
+    /// Sedgewick's `4^k + 3 * 2^(k-1) + 1`: 1, 8, 23, 77, 281, ...
+    Sedgewick,
+}
+
+impl GapSequence {
+    /// The gaps used for a slice of length `len`, in decreasing order and
+    /// ending with 1 (empty when `len < 2`).
+    pub fn gaps(&self, len: usize) -> Vec<usize> {
+        let mut gaps = Vec::new();
+        match *self {
+            GapSequence::Shell => {
+                let mut gap = len / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+                return gaps;
+            }
+            GapSequence::Knuth => {
+                let mut gap = 1;
+                while gap < len {
+                    gaps.push(gap);
+                    gap = 3 * gap + 1;
+                }
+            }
+            GapSequence::Ciura => {
+                let mut gap = 1;
+                let mut k = 0;
+                while gap < len {
+                    gaps.push(gap);
+                    k += 1;
+                    gap = if k < CIURA_GAPS.len() {
+                        CIURA_GAPS[k]
+                    } else {
+                        gap * 9 / 4
+                    };
+                }
+            }
+            GapSequence::Sedgewick => {
+                let mut gap = 1;
+                let mut k = 0;
+                while gap < len {
+                    gaps.push(gap);
+                    k += 1;
+                    gap = (1 << (2 * k)) + 3 * (1 << (k - 1)) + 1;
+                }
+            }
+        }
+        gaps.reverse();
+        gaps
+    }
+}
+
+/// Shell sort with `GapSequence::Ciura`.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::shell::shell_sort;
+///
+/// let mut source = vec![3, 2, 5, 1, 4];
+/// shell_sort(&mut source);
+/// assert_eq!(source, vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn shell_sort<T: PartialEq + PartialOrd>(source: &mut [T]) {
+    shell_sort_with(source, GapSequence::Ciura);
+}
+
+/// Like `shell_sort`, ordering elements with `compare`.
+pub fn shell_sort_by<T, F>(source: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    sort_by_less(source, GapSequence::Ciura, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `shell_sort`, ordering elements by the key `key` extracts.
+pub fn shell_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    sort_by_less(source, GapSequence::Ciura, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+/// Shell sort: insertion sort over elements `gap` apart, for every gap of
+/// `gaps` down to 1.
+pub fn shell_sort_with<T: PartialEq + PartialOrd>(source: &mut [T], gaps: GapSequence) {
+    sort_by_less(source, gaps, &mut |a: &T, b: &T| a < b);
+}
+
+fn sort_by_less<T, F>(source: &mut [T], gaps: GapSequence, is_less: &mut F)
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    for gap in gaps.gaps(source.len()) {
+        for i in gap..source.len() {
+            let mut j = i;
+            while j >= gap && is_less(&source[j], &source[j - gap]) {
+                source.swap(j, j - gap);
+                j -= gap;
+            }
+        }
+    }
+}
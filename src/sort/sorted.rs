@@ -0,0 +1,221 @@
+use std::cmp::{Ordering, PartialEq, PartialOrd};
+
+use heap::binary_heap::BinaryHeap;
+
+/// Whether no element is less than the one before it.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::sorted::is_sorted;
+///
+/// assert!(is_sorted(&[1, 2, 2, 3]));
+/// assert!(!is_sorted(&[1, 3, 2]));
+/// ```
+pub fn is_sorted<T: PartialOrd>(source: &[T]) -> bool {
+    source.windows(2).all(|w| w[1].partial_cmp(&w[0]) != Some(Ordering::Less))
+}
+
+/// Whether no element compares `Greater` than the one after it.
+pub fn is_sorted_by<T, F>(source: &[T], mut compare: F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    source.windows(2).all(|w| compare(&w[0], &w[1]) != Ordering::Greater)
+}
+
+/// Whether the keys `key` extracts are sorted.
+pub fn is_sorted_by_key<T, K, F>(source: &[T], mut key: F) -> bool
+where
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    source
+        .windows(2)
+        .all(|w| key(&w[1]).partial_cmp(&key(&w[0])) != Some(Ordering::Less))
+}
+
+/// Merge two sorted slices into a new sorted vector. Stable: of equal
+/// elements, those of `a` come first.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::sorted::merge;
+///
+/// assert_eq!(merge(&[1, 4, 6], &[2, 3, 7]), vec![1, 2, 3, 4, 6, 7]);
+/// ```
+pub fn merge<T: PartialOrd + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    merge_by_less(a, b, &mut |x: &T, y: &T| x < y)
+}
+
+/// Like `merge`, for slices sorted by `compare`.
+pub fn merge_by<T, F>(a: &[T], b: &[T], mut compare: F) -> Vec<T>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_by_less(a, b, &mut |x: &T, y: &T| compare(x, y) == Ordering::Less)
+}
+
+/// Heap order of `(item, iterator index)` heads, see `compare_heads`.
+type HeadOrder<T> = fn(&(T, usize), &(T, usize)) -> Ordering;
+
+/// Iterator merging sorted iterators, see `k_way_merge`.
+pub struct KWayMerge<I: Iterator> {
+    iters: Vec<I>,
+    heads: BinaryHeap<(I::Item, usize)>,
+}
+
+/// Merge any number of sorted iterators lazily, keeping only the next
+/// item of each in a heap: O(log k) per item for `k` iterators. Stable:
+/// of equal items, those of earlier iterators come first.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::sorted::k_way_merge;
+///
+/// let merged: Vec<i32> = k_way_merge(vec![
+///     vec![1, 5, 9].into_iter(),
+///     vec![2, 3].into_iter(),
+///     vec![4, 8].into_iter(),
+/// ]).collect();
+/// assert_eq!(merged, vec![1, 2, 3, 4, 5, 8, 9]);
+/// ```
+pub fn k_way_merge<I>(iters: Vec<I>) -> KWayMerge<I>
+where
+    I: Iterator,
+    I::Item: PartialOrd,
+{
+    let mut iters = iters;
+    let mut heads = Vec::with_capacity(iters.len());
+    for (i, iter) in iters.iter_mut().enumerate() {
+        if let Some(item) = iter.next() {
+            heads.push((item, i));
+        }
+    }
+    let compare: HeadOrder<I::Item> = compare_heads;
+    KWayMerge {
+        iters,
+        heads: BinaryHeap::from_vec(2, heads, compare),
+    }
+}
+
+impl<I> Iterator for KWayMerge<I>
+where
+    I: Iterator,
+    I::Item: PartialOrd,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let (item, i) = self.heads.pop()?;
+        if let Some(next) = self.iters[i].next() {
+            self.heads.push((next, i));
+        }
+        Some(item)
+    }
+}
+
+/// Move the first of every run of equal elements of a sorted slice to the
+/// front, returning how many there are. The order of the rest is
+/// unspecified.
+///
+/// # Example
+/// ```
+/// use algorithm::sort::sorted::dedup_sorted;
+///
+/// let mut source = vec![1, 1, 2, 3, 3, 3, 4];
+/// let len = dedup_sorted(&mut source);
+/// source.truncate(len);
+/// assert_eq!(source, vec![1, 2, 3, 4]);
+/// ```
+pub fn dedup_sorted<T: PartialEq>(source: &mut [T]) -> usize {
+    if source.is_empty() {
+        return 0;
+    }
+    // source[..unique] holds the distinct elements seen so far
+    let mut unique = 1;
+    for i in 1..source.len() {
+        if source[i] != source[unique - 1] {
+            source.swap(unique, i);
+            unique += 1;
+        }
+    }
+    unique
+}
+
+/// Number of pairs `i < j` with `source[i] > source[j]`, counted while
+/// merge sorting a copy: O(n log n).
+///
+/// # Example
+/// ```
+/// use algorithm::sort::sorted::count_inversions;
+///
+/// assert_eq!(count_inversions(&[2, 4, 1, 3, 5]), 3);
+/// ```
+pub fn count_inversions<T: PartialOrd + Clone>(source: &[T]) -> usize {
+    let mut v = source.to_vec();
+    let mut buffer = Vec::with_capacity(v.len());
+    sort_counting(&mut v, &mut buffer)
+}
+
+fn merge_by_less<T, F>(a: &[T], b: &[T], is_less: &mut F) -> Vec<T>
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> bool,
+{
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if is_less(&b[j], &a[i]) {
+            merged.push(b[j].clone());
+            j += 1;
+        } else {
+            merged.push(a[i].clone());
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&a[i..]);
+    merged.extend_from_slice(&b[j..]);
+    merged
+}
+
+/// Heads ordered by item, then by iterator index for stability.
+fn compare_heads<T: PartialOrd>(a: &(T, usize), b: &(T, usize)) -> Ordering {
+    if a.0 < b.0 {
+        Ordering::Less
+    } else if b.0 < a.0 {
+        Ordering::Greater
+    } else {
+        a.1.cmp(&b.1)
+    }
+}
+
+/// Merge sort `source`, returning the number of inversions it had.
+fn sort_counting<T: PartialOrd + Clone>(source: &mut [T], buffer: &mut Vec<T>) -> usize {
+    let len = source.len();
+    if len < 2 {
+        return 0;
+    }
+
+    let mid = len / 2;
+    let mut count = sort_counting(&mut source[..mid], buffer);
+    count += sort_counting(&mut source[mid..], buffer);
+
+    buffer.clear();
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < len {
+        if source[j] < source[i] {
+            // source[j] jumps over everything left in the left half
+            count += mid - i;
+            buffer.push(source[j].clone());
+            j += 1;
+        } else {
+            buffer.push(source[i].clone());
+            i += 1;
+        }
+    }
+    buffer.extend_from_slice(&source[i..mid]);
+    buffer.extend_from_slice(&source[j..]);
+    source.clone_from_slice(buffer);
+    count
+}
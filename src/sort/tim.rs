@@ -0,0 +1,305 @@
+use std::cmp::{min, Ordering, PartialEq, PartialOrd};
+
+use sort::insertion::binary_insertion_sort_by_less;
+
+/// Slices shorter than this are sorted by binary insertion sort alone.
+const MIN_MERGE: usize = 64;
+
+/// Initial number of consecutive wins of one run before a merge switches
+/// to galloping mode.
+const MIN_GALLOP: usize = 7;
+
+/// Stable natural merge sort (TimSort).
+///
+/// Existing ascending and strictly descending runs of the input are
+/// detected and merged, short runs are extended by binary insertion sort,
+/// and merges switch to galloping (exponential search) when one run keeps
+/// winning, so partially sorted input is sorted in close to O(n).
+///
+/// # Example
+/// ```
+/// use algorithm::sort::tim::tim_sort;
+///
+/// let mut source = vec![1, 2, 3, 9, 8, 7, 4, 5, 6];
+/// tim_sort(&mut source);
+/// assert_eq!(source, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+pub fn tim_sort<T: PartialEq + PartialOrd + Clone>(source: &mut [T]) {
+    sort_by_less(source, &mut |a: &T, b: &T| a < b);
+}
+
+/// Like `tim_sort`, ordering elements with `compare`.
+pub fn tim_sort_by<T, F>(source: &mut [T], mut compare: F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> Ordering,
+{
+    sort_by_less(source, &mut |a: &T, b: &T| compare(a, b) == Ordering::Less);
+}
+
+/// Like `tim_sort`, ordering elements by the key `key` extracts.
+pub fn tim_sort_by_key<T, K, F>(source: &mut [T], mut key: F)
+where
+    T: Clone,
+    K: PartialOrd,
+    F: FnMut(&T) -> K,
+{
+    sort_by_less(source, &mut |a: &T, b: &T| key(a) < key(b));
+}
+
+/// A run on the stack: `source[start..start + len]` is sorted.
+#[derive(Clone, Copy)]
+struct Run {
+    start: usize,
+    len: usize,
+}
+
+pub(crate) fn sort_by_less<T, F>(source: &mut [T], is_less: &mut F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = source.len();
+    if len < 2 {
+        return;
+    }
+    if len < MIN_MERGE {
+        let sorted = count_run(source, is_less);
+        binary_insertion_sort_by_less(source, sorted, is_less);
+        return;
+    }
+
+    let min_run = min_run_length(len);
+    let mut runs: Vec<Run> = Vec::new();
+    let mut min_gallop = MIN_GALLOP;
+    let mut start = 0;
+    while start < len {
+        let mut run_len = count_run(&mut source[start..], is_less);
+        if run_len < min_run {
+            // extend short runs to `min_run` elements
+            let end = min(start + min_run, len);
+            binary_insertion_sort_by_less(&mut source[start..end], run_len, is_less);
+            run_len = end - start;
+        }
+
+        runs.push(Run {
+            start,
+            len: run_len,
+        });
+        merge_collapse(source, &mut runs, &mut min_gallop, is_less);
+        start += run_len;
+    }
+
+    // merge everything left on the stack
+    while runs.len() > 1 {
+        let n = runs.len();
+        let at = if n >= 3 && runs[n - 3].len < runs[n - 1].len {
+            n - 3
+        } else {
+            n - 2
+        };
+        merge_at(source, &mut runs, at, &mut min_gallop, is_less);
+    }
+}
+
+/// Minimum run length: `n` shifted down to [32, 64), rounded up when any
+/// shifted out bit is set, so that `n / min_run` is (close to) a power of two.
+fn min_run_length(mut n: usize) -> usize {
+    let mut r = 0;
+    while n >= MIN_MERGE {
+        r |= n & 1;
+        n >>= 1;
+    }
+    n + r
+}
+
+/// Length of the run at the beginning of the slice. Strictly descending
+/// runs are reversed in place (strictly, so equal elements are never
+/// reordered).
+fn count_run<T, F>(source: &mut [T], is_less: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = source.len();
+    if len < 2 {
+        return len;
+    }
+
+    let mut end = 2;
+    if is_less(&source[1], &source[0]) {
+        while end < len && is_less(&source[end], &source[end - 1]) {
+            end += 1;
+        }
+        source[..end].reverse();
+    } else {
+        while end < len && !is_less(&source[end], &source[end - 1]) {
+            end += 1;
+        }
+    }
+    end
+}
+
+/// Keep the run lengths on the stack growing at least like the Fibonacci
+/// numbers (checking the top four runs, see the 2015 TimSort invariant bug).
+fn merge_collapse<T, F>(
+    source: &mut [T],
+    runs: &mut Vec<Run>,
+    min_gallop: &mut usize,
+    is_less: &mut F,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> bool,
+{
+    while runs.len() > 1 {
+        let n = runs.len();
+        if (n >= 3 && runs[n - 3].len <= runs[n - 2].len + runs[n - 1].len)
+            || (n >= 4 && runs[n - 4].len <= runs[n - 3].len + runs[n - 2].len)
+        {
+            let at = if runs[n - 3].len < runs[n - 1].len {
+                n - 3
+            } else {
+                n - 2
+            };
+            merge_at(source, runs, at, min_gallop, is_less);
+        } else if runs[n - 2].len <= runs[n - 1].len {
+            merge_at(source, runs, n - 2, min_gallop, is_less);
+        } else {
+            break;
+        }
+    }
+}
+
+/// Merge the runs `at` and `at + 1` of the stack.
+fn merge_at<T, F>(
+    source: &mut [T],
+    runs: &mut Vec<Run>,
+    at: usize,
+    min_gallop: &mut usize,
+    is_less: &mut F,
+) where
+    T: Clone,
+    F: FnMut(&T, &T) -> bool,
+{
+    let a = runs[at];
+    let b = runs.remove(at + 1);
+    runs[at].len = a.len + b.len;
+
+    let region = &mut source[a.start..b.start + b.len];
+    let mid = a.len;
+
+    // elements of `a` not greater than b[0] are already in place,
+    // elements of `b` not less than the last of `a` as well
+    let skip = gallop(&region[..mid], |x| !is_less(&region[mid], x));
+    let keep = gallop(&region[mid..], |x| is_less(x, &region[mid - 1]));
+    if skip == mid || keep == 0 {
+        return;
+    }
+    merge_lo(&mut region[skip..mid + keep], mid - skip, min_gallop, is_less);
+}
+
+/// Merge `[0, mid)` and `[mid, len)` with a copy of the left run, galloping
+/// once either run wins `min_gallop` times in a row.
+fn merge_lo<T, F>(source: &mut [T], mid: usize, min_gallop: &mut usize, is_less: &mut F)
+where
+    T: Clone,
+    F: FnMut(&T, &T) -> bool,
+{
+    let len = source.len();
+    let left = source[..mid].to_vec();
+    let mut i = 0; // next of `left`
+    let mut j = mid; // next of the right run
+    let mut k = 0; // next position to write
+
+    'outer: while i < left.len() && j < len {
+        // one pair at a time until a run starts winning consistently
+        let mut count_left = 0;
+        let mut count_right = 0;
+        while count_left < *min_gallop && count_right < *min_gallop {
+            if is_less(&source[j], &left[i]) {
+                source.swap(k, j);
+                j += 1;
+                count_right += 1;
+                count_left = 0;
+            } else {
+                source[k] = left[i].clone();
+                i += 1;
+                count_left += 1;
+                count_right = 0;
+            }
+            k += 1;
+            if i == left.len() || j == len {
+                break 'outer;
+            }
+        }
+
+        // galloping: move whole blocks found by exponential search
+        loop {
+            count_left = gallop(&left[i..], |x| !is_less(&source[j], x));
+            for _ in 0..count_left {
+                source[k] = left[i].clone();
+                i += 1;
+                k += 1;
+            }
+            if i == left.len() {
+                break 'outer;
+            }
+
+            count_right = gallop(&source[j..], |x| is_less(x, &left[i]));
+            for _ in 0..count_right {
+                source.swap(k, j);
+                j += 1;
+                k += 1;
+            }
+            if j == len {
+                break 'outer;
+            }
+
+            if count_left < MIN_GALLOP && count_right < MIN_GALLOP {
+                // galloping does not pay off anymore, make it harder to re-enter
+                *min_gallop += 1;
+                break;
+            }
+            if *min_gallop > 1 {
+                *min_gallop -= 1;
+            }
+        }
+    }
+
+    // the rest of the right run is already in place
+    while i < left.len() {
+        source[k] = left[i].clone();
+        i += 1;
+        k += 1;
+    }
+}
+
+/// Number of leading elements of `source` satisfying `pred`, which must be
+/// true for a prefix of the slice and false for the rest.
+///
+/// Probes 1, 3, 7, 15, ... elements before binary searching, so it costs
+/// O(log k) for an answer of k.
+fn gallop<T, P>(source: &[T], mut pred: P) -> usize
+where
+    P: FnMut(&T) -> bool,
+{
+    let len = source.len();
+    let mut last = 0;
+    let mut ofs = 1;
+    while ofs <= len && pred(&source[ofs - 1]) {
+        last = ofs;
+        ofs = 2 * ofs + 1;
+    }
+
+    // answer is in [last, min(ofs - 1, len)]
+    let mut lo = last;
+    let mut hi = min(ofs - 1, len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(&source[mid]) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
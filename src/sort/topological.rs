@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use heap::binary_heap::BinaryHeap;
+
+/// Order the nodes of a directed graph so that every node comes before
+/// all the nodes it has an edge to, by Kahn's algorithm.
+///
+/// `graph` maps each node to the nodes it has an edge to, the shape used
+/// by `search::breadth::breadth_search`; nodes which only appear as
+/// targets are included as well. For a map from each task to the tasks it
+/// depends on, reverse the order to get dependencies first.
+///
+/// When the graph has a cycle, returns `Err` with the nodes of one: each
+/// has an edge to the next, and the last one to the first.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use algorithm::sort::topological::topological_sort;
+///
+/// let mut graph = HashMap::new();
+/// graph.insert("fetch", vec!["build"]);
+/// graph.insert("build", vec!["test", "package"]);
+/// graph.insert("test", vec!["package"]);
+/// assert_eq!(topological_sort(&graph), Ok(vec!["fetch", "build", "test", "package"]));
+///
+/// graph.insert("package", vec!["build"]);
+/// assert!(topological_sort(&graph).is_err());
+/// ```
+pub fn topological_sort<T>(graph: &HashMap<T, Vec<T>>) -> Result<Vec<T>, Vec<T>>
+where
+    T: Hash + Eq + Clone,
+{
+    kahn(graph, VecDeque::new())
+}
+
+/// Like `topological_sort`, by depth-first search: a node is placed once
+/// all the nodes reachable from it are.
+pub fn topological_sort_dfs<T>(graph: &HashMap<T, Vec<T>>) -> Result<Vec<T>, Vec<T>>
+where
+    T: Hash + Eq + Clone,
+{
+    let mut state: HashMap<T, Visit> = HashMap::new();
+    let mut order = Vec::new();
+    for root in nodes(graph) {
+        if state.contains_key(&root) {
+            continue;
+        }
+
+        // the path from `root`, with the index of the next edge to follow
+        state.insert(root.clone(), Visit::InProgress);
+        let mut path = vec![(root, 0)];
+        while let Some((node, next)) = path.pop() {
+            let targets = successors(graph, &node);
+            if next == targets.len() {
+                state.insert(node.clone(), Visit::Done);
+                order.push(node);
+                continue;
+            }
+
+            let target = targets[next].clone();
+            path.push((node, next + 1));
+            match state.get(&target) {
+                Some(&Visit::Done) => {}
+                Some(&Visit::InProgress) => {
+                    // back edge: the cycle is the path from `target` on
+                    let from = path.iter().position(|(n, _)| *n == target).unwrap();
+                    return Err(path.drain(from..).map(|(n, _)| n).collect());
+                }
+                None => {
+                    state.insert(target.clone(), Visit::InProgress);
+                    path.push((target, 0));
+                }
+            }
+        }
+    }
+
+    order.reverse();
+    Ok(order)
+}
+
+/// Like `topological_sort`, returning the lexicographically smallest
+/// order: whenever several nodes are free to go next, the least does.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use algorithm::sort::topological::topological_sort_lexicographic;
+///
+/// let mut graph = HashMap::new();
+/// graph.insert(3, vec![1]);
+/// graph.insert(2, vec![1]);
+/// graph.insert(4, vec![]);
+/// assert_eq!(topological_sort_lexicographic(&graph), Ok(vec![2, 3, 1, 4]));
+/// ```
+pub fn topological_sort_lexicographic<T>(graph: &HashMap<T, Vec<T>>) -> Result<Vec<T>, Vec<T>>
+where
+    T: Hash + Eq + Ord + Clone,
+{
+    kahn(graph, BinaryHeap::new())
+}
+
+enum Visit {
+    InProgress,
+    Done,
+}
+
+/// Nodes whose edges have all been followed, waiting to be placed.
+trait Ready<T> {
+    fn put(&mut self, node: T);
+    fn take(&mut self) -> Option<T>;
+}
+
+impl<T> Ready<T> for VecDeque<T> {
+    fn put(&mut self, node: T) {
+        self.push_back(node);
+    }
+
+    fn take(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+}
+
+impl<T: Ord> Ready<T> for BinaryHeap<T> {
+    fn put(&mut self, node: T) {
+        self.push(node);
+    }
+
+    fn take(&mut self) -> Option<T> {
+        self.pop()
+    }
+}
+
+/// Kahn's algorithm: repeatedly place a node no remaining node has an
+/// edge to, and remove its edges.
+fn kahn<T, R>(graph: &HashMap<T, Vec<T>>, mut ready: R) -> Result<Vec<T>, Vec<T>>
+where
+    T: Hash + Eq + Clone,
+    R: Ready<T>,
+{
+    let nodes = nodes(graph);
+    let mut in_degree: HashMap<T, usize> = nodes.iter().map(|n| (n.clone(), 0)).collect();
+    for targets in graph.values() {
+        for target in targets {
+            *in_degree.get_mut(target).unwrap() += 1;
+        }
+    }
+    for node in &nodes {
+        if in_degree[node] == 0 {
+            ready.put(node.clone());
+        }
+    }
+
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(node) = ready.take() {
+        for target in successors(graph, &node) {
+            let degree = in_degree.get_mut(target).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                ready.put(target.clone());
+            }
+        }
+        order.push(node);
+    }
+
+    if order.len() == nodes.len() {
+        Ok(order)
+    } else {
+        // the nodes left are on or behind a cycle, let the DFS find one
+        match topological_sort_dfs(graph) {
+            Err(cycle) => Err(cycle),
+            Ok(_) => unreachable!("Kahn's algorithm stopped without a cycle"),
+        }
+    }
+}
+
+/// Every node of the graph: the keys, then the targets which are not keys.
+fn nodes<T: Hash + Eq + Clone>(graph: &HashMap<T, Vec<T>>) -> Vec<T> {
+    let mut seen: HashSet<&T> = graph.keys().collect();
+    let mut nodes: Vec<T> = graph.keys().cloned().collect();
+    for targets in graph.values() {
+        for target in targets {
+            if seen.insert(target) {
+                nodes.push(target.clone());
+            }
+        }
+    }
+    nodes
+}
+
+fn successors<'a, T: Hash + Eq>(graph: &'a HashMap<T, Vec<T>>, node: &T) -> &'a [T] {
+    match graph.get(node) {
+        Some(targets) => targets,
+        None => &[],
+    }
+}
@@ -1,6 +1,7 @@
 use std::cmp::max;
 use std::fmt::{Debug, Display};
 use std::mem::swap;
+use tree::trace::{Trace, TraceEvent};
 
 const ALLOWED_IMBALANCE: isize = 1;
 
@@ -78,7 +79,7 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
         }
     }
 
-    fn balance(&mut self) {
+    fn balance(&mut self, trace: &mut Trace<T>) {
         let mut rotate_case = RotateCase::Unknown;
         match self {
             &mut AVLTree::Empty => return,
@@ -106,16 +107,17 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
         }
 
         match rotate_case {
-            RotateCase::RotateLeftChild => self.rotate_left_child(),
-            RotateCase::DoubleLeftChild => self.double_left_child(),
-            RotateCase::RotateRightChild => self.rotate_right_child(),
-            RotateCase::DoubleRightChild => self.double_right_child(),
+            RotateCase::RotateLeftChild => self.rotate_left_child(trace),
+            RotateCase::DoubleLeftChild => self.double_left_child(trace),
+            RotateCase::RotateRightChild => self.rotate_right_child(trace),
+            RotateCase::DoubleRightChild => self.double_right_child(trace),
             _ => {}
         }
-        self.update_height();
+        self.update_height(trace);
     }
 
-    fn rotate_left_child(&mut self) {
+    /// rotate left child (LL), which is a right rotation of the current node
+    fn rotate_left_child(&mut self, trace: &mut Trace<T>) {
         let mut x: AVLTree<T> = AVLTree::Empty;
         let mut y: AVLTree<T> = AVLTree::Empty;
         let mut z: AVLTree<T> = AVLTree::Empty;
@@ -127,6 +129,7 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
         match self {
             &mut AVLTree::Empty => return,
             &mut AVLTree::Node(ref mut v, _, box ref mut l, box ref mut r) => {
+                trace.record(|| TraceEvent::RotateRight { value: v.clone() });
                 k1_v = l.get_value().unwrap();
                 k2_v = v.clone();
                 swap(l.left_mut().unwrap(), &mut x);
@@ -137,19 +140,19 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
 
         // generate lower k2 node
         k2 = AVLTree::Node(k2_v, 0, Box::new(y), Box::new(z));
-        k2.update_height();
+        k2.update_height(trace);
 
         // generate k1 node
         k1 = AVLTree::Node(k1_v, 0, Box::new(x), Box::new(k2));
-        k1.update_height();
+        k1.update_height(trace);
 
         *self = k1;
     }
 
-    /// rotate right child (RR)
+    /// rotate right child (RR), which is a left rotation of the current node
     /// Rust 由于声明周期的关系，不适合做类似指针赋值交换这样测操作
     /// 这里采用swap保存子树，然后对局部根节点做重组的方式进行
-    fn rotate_right_child(&mut self) {
+    fn rotate_right_child(&mut self, trace: &mut Trace<T>) {
         let mut x: AVLTree<T> = AVLTree::Empty;
         let mut y: AVLTree<T> = AVLTree::Empty;
         let mut z: AVLTree<T> = AVLTree::Empty;
@@ -161,6 +164,7 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
         match self {
             &mut AVLTree::Empty => return,
             &mut AVLTree::Node(ref mut v, _, box ref mut l, box ref mut r) => {
+                trace.record(|| TraceEvent::RotateLeft { value: v.clone() });
                 k2_v = r.get_value().unwrap();
                 k1_v = v.clone();
                 swap(r.left_mut().unwrap(), &mut y);
@@ -171,54 +175,79 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
 
         // generate lower k2 node
         k1 = AVLTree::Node(k1_v, 0, Box::new(x), Box::new(y));
-        k1.update_height();
+        k1.update_height(trace);
 
         // generate lower k1 node
         k2 = AVLTree::Node(k2_v, 0, Box::new(k1), Box::new(z));
-        k2.update_height();
+        k2.update_height(trace);
 
         *self = k2;
     }
 
-    fn double_left_child(&mut self) {
+    fn double_left_child(&mut self, trace: &mut Trace<T>) {
         match self {
             &mut AVLTree::Empty => return,
             &mut AVLTree::Node(_, _, box ref mut l, _) => {
-                l.rotate_right_child();
+                l.rotate_right_child(trace);
             }
         }
-        self.rotate_left_child();
+        self.rotate_left_child(trace);
     }
 
-    fn double_right_child(&mut self) {
+    fn double_right_child(&mut self, trace: &mut Trace<T>) {
         match self {
             &mut AVLTree::Empty => return,
             &mut AVLTree::Node(_, _, _, box ref mut r) => {
-                r.rotate_left_child();
+                r.rotate_left_child(trace);
             }
         }
-        self.rotate_right_child();
+        self.rotate_right_child(trace);
     }
 
     pub fn insert(&mut self, value: T) -> &mut Self {
+        self.insert_with(value, &mut Trace::disabled());
+        self
+    }
+
+    /// Same as `insert`, returning every step taken by the operation.
+    pub fn insert_traced(&mut self, value: T) -> Vec<TraceEvent<T>> {
+        let mut trace = Trace::enabled();
+        self.insert_with(value, &mut trace);
+        trace.into_events()
+    }
+
+    fn insert_with(&mut self, value: T, trace: &mut Trace<T>) {
         match *self {
             AVLTree::Empty => {
                 *self = AVLTree::Node(value, 1, Box::new(AVLTree::Empty), Box::new(AVLTree::Empty))
             }
             AVLTree::Node(ref old_value, _, box ref mut l, box ref mut r) => {
+                trace.record(|| TraceEvent::Visit {
+                    value: old_value.clone(),
+                });
                 if value < *old_value {
-                    l.insert(value);
+                    l.insert_with(value, trace);
                 } else {
-                    r.insert(value);
+                    r.insert_with(value, trace);
                 }
             }
         }
-        self.balance(); // blaance tree
+        self.balance(trace); // blaance tree
+    }
 
+    pub fn remove(&mut self, value: T) -> &mut Self {
+        self.remove_with(value, &mut Trace::disabled());
         self
     }
 
-    pub fn remove(&mut self, value: T) -> &mut Self {
+    /// Same as `remove`, returning every step taken by the operation.
+    pub fn remove_traced(&mut self, value: T) -> Vec<TraceEvent<T>> {
+        let mut trace = Trace::enabled();
+        self.remove_with(value, &mut trace);
+        trace.into_events()
+    }
+
+    fn remove_with(&mut self, value: T, trace: &mut Trace<T>) {
         let child_num = self.get_child_num();
         let mut node = AVLTree::Empty;
         let mut is_swaped = false;
@@ -226,11 +255,11 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
         match *self {
             AVLTree::Empty => {}
             AVLTree::Node(ref mut v, _, box ref mut l, box ref mut r) => {
-                // println!("childs of <{:?}>={:?}", (*v).clone(), child_num);
+                trace.record(|| TraceEvent::Visit { value: v.clone() });
                 if value < *v {
-                    l.remove(value);
+                    l.remove_with(value, trace);
                 } else if value > *v {
-                    r.remove(value);
+                    r.remove_with(value, trace);
                 } else {
                     if is_leaf && *v == value {
                         // a leaf node
@@ -239,7 +268,7 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
                         // 2 childs, put min of right branch as the current value,
                         // then remove it from right branch
                         *v = r.find_min().unwrap();
-                        r.remove(v.clone());
+                        r.remove_with(v.clone(), trace);
                     } else {
                         if !l.is_empty() {
                             is_swaped = true;
@@ -257,9 +286,7 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
         if is_swaped {
             *self = node;
         }
-        self.balance();
-
-        self
+        self.balance(trace);
     }
 
     pub fn get_value(&self) -> Option<T> {
@@ -296,11 +323,18 @@ impl<T: PartialOrd + Clone + Debug + Display> AVLTree<T> {
     //     self
     // }
 
-    fn update_height(&mut self) -> &mut Self {
+    fn update_height(&mut self, trace: &mut Trace<T>) -> &mut Self {
         match self {
             &mut AVLTree::Empty => {}
-            &mut AVLTree::Node(_, ref mut h, box ref l, box ref r) => {
-                *h = max(l.height(), r.height()) + 1;
+            &mut AVLTree::Node(ref v, ref mut h, box ref l, box ref r) => {
+                let height = max(l.height(), r.height()) + 1;
+                if *h != height {
+                    *h = height;
+                    trace.record(|| TraceEvent::UpdateHeight {
+                        value: v.clone(),
+                        height,
+                    });
+                }
             }
         }
         self
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Union-find over the elements `0..len`, with union by rank and path
+/// compression.
+///
+/// # Example
+/// ```
+/// use algorithm::tree::disjoint_set::DisjointSet;
+///
+/// let mut set = DisjointSet::new(5);
+/// set.union(0, 1);
+/// set.union(3, 4);
+/// assert!(set.connected(0, 1));
+/// assert!(!set.connected(1, 3));
+/// assert_eq!(set.components(), vec![vec![0, 1], vec![2], vec![3, 4]]);
+/// ```
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Add a new singleton element, returning its index.
+    pub fn add(&mut self) -> usize {
+        let index = self.parent.len();
+        self.parent.push(index);
+        self.rank.push(0);
+        self.count += 1;
+        index
+    }
+
+    /// Representative of the component containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // path compression: hang every node on the way directly under root
+        let mut node = x;
+        while self.parent[node] != root {
+            let next = self.parent[node];
+            self.parent[node] = root;
+            node = next;
+        }
+        root
+    }
+
+    /// Merge the components of `x` and `y`, returning `false` if they were
+    /// already the same component.
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let rx = self.find(x);
+        let ry = self.find(y);
+        if rx == ry {
+            return false;
+        }
+
+        // attach the lower tree under the higher one
+        if self.rank[rx] < self.rank[ry] {
+            self.parent[rx] = ry;
+        } else if self.rank[rx] > self.rank[ry] {
+            self.parent[ry] = rx;
+        } else {
+            self.parent[ry] = rx;
+            self.rank[rx] += 1;
+        }
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.count
+    }
+
+    /// Every component, ordered by its smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_of_root = HashMap::new();
+        for x in 0..self.len() {
+            let root = self.find(x);
+            let group = *group_of_root.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(x);
+        }
+        groups
+    }
+}
+
+/// `DisjointSet` over arbitrary keys, which are added on first use.
+///
+/// # Example
+/// ```
+/// use algorithm::tree::disjoint_set::DisjointSetMap;
+///
+/// let mut set = DisjointSetMap::new();
+/// set.union("you", "alice");
+/// set.union("bob", "anuj");
+/// set.union("alice", "bob");
+/// assert!(set.connected(&"you", &"anuj"));
+/// assert_eq!(set.component_count(), 1);
+/// ```
+pub struct DisjointSetMap<K: Hash + Eq> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+    set: DisjointSet,
+}
+
+impl<K: Hash + Eq + Clone> Default for DisjointSetMap<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone> DisjointSetMap<K> {
+    pub fn new() -> Self {
+        DisjointSetMap {
+            indices: HashMap::new(),
+            keys: Vec::new(),
+            set: DisjointSet::new(0),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// Add `key` as a singleton component, returning `false` if it is
+    /// already known.
+    pub fn make_set(&mut self, key: K) -> bool {
+        if self.indices.contains_key(&key) {
+            return false;
+        }
+        self.index_of(key);
+        true
+    }
+
+    /// Representative key of the component containing `key`.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let index = *self.indices.get(key)?;
+        let root = self.set.find(index);
+        Some(&self.keys[root])
+    }
+
+    /// Merge the components of `x` and `y`, returning `false` if they were
+    /// already the same component.
+    pub fn union(&mut self, x: K, y: K) -> bool {
+        let x = self.index_of(x);
+        let y = self.index_of(y);
+        self.set.union(x, y)
+    }
+
+    /// Whether both keys are known and in the same component.
+    pub fn connected(&mut self, x: &K, y: &K) -> bool {
+        match (self.indices.get(x), self.indices.get(y)) {
+            (Some(&x), Some(&y)) => self.set.connected(x, y),
+            _ => false,
+        }
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.set.component_count()
+    }
+
+    /// Every component, ordered by the first insertion of its keys.
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        let keys = &self.keys;
+        self.set
+            .components()
+            .into_iter()
+            .map(|group| group.into_iter().map(|i| keys[i].clone()).collect())
+            .collect()
+    }
+
+    fn index_of(&mut self, key: K) -> usize {
+        if let Some(&i) = self.indices.get(&key) {
+            return i;
+        }
+        let i = self.set.add();
+        self.keys.push(key.clone());
+        self.indices.insert(key, i);
+        i
+    }
+}
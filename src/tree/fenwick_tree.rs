@@ -0,0 +1,109 @@
+use std::ops::{Add, Sub};
+
+/// Fenwick tree (binary indexed tree) over `len` values.
+///
+/// All ranges are half-open: `range_sum(start, end)` covers `[start, end)`.
+///
+/// # Example
+/// ```
+/// use algorithm::tree::fenwick_tree::FenwickTree;
+///
+/// let mut tree = FenwickTree::from_slice(&[1, 2, 3, 4, 5]);
+/// tree.add(2, 10);
+/// assert_eq!(tree.prefix_sum(3), 16);
+/// assert_eq!(tree.range_sum(1, 4), 19);
+/// ```
+pub struct FenwickTree<T> {
+    // 1-based internal array, tree[i] covers (i - lowbit(i), i]
+    tree: Vec<T>,
+}
+
+fn lowbit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T>> FenwickTree<T> {
+    pub fn new(len: usize) -> Self {
+        FenwickTree {
+            tree: vec![T::default(); len + 1],
+        }
+    }
+
+    /// Build the tree in O(n) from initial values.
+    pub fn from_slice(values: &[T]) -> Self {
+        let mut tree = vec![T::default(); values.len() + 1];
+        for (i, v) in values.iter().enumerate() {
+            tree[i + 1] = tree[i + 1] + *v;
+            let parent = i + 1 + lowbit(i + 1);
+            if parent < tree.len() {
+                tree[parent] = tree[parent] + tree[i + 1];
+            }
+        }
+        FenwickTree { tree }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add `delta` to the value at `index`.
+    pub fn add(&mut self, index: usize, delta: T) {
+        assert!(index < self.len(), "index out of range");
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i] + delta;
+            i += lowbit(i);
+        }
+    }
+
+    /// Replace the value at `index`.
+    pub fn set(&mut self, index: usize, value: T) {
+        let old = self.range_sum(index, index + 1);
+        self.add(index, value - old);
+    }
+
+    /// Sum of the first `end` values.
+    pub fn prefix_sum(&self, end: usize) -> T {
+        assert!(end <= self.len(), "index out of range");
+        let mut sum = T::default();
+        let mut i = end;
+        while i > 0 {
+            sum = sum + self.tree[i];
+            i -= lowbit(i);
+        }
+        sum
+    }
+
+    pub fn range_sum(&self, start: usize, end: usize) -> T {
+        assert!(start <= end, "start is greater than end");
+        self.prefix_sum(end) - self.prefix_sum(start)
+    }
+}
+
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T> + PartialOrd> FenwickTree<T> {
+    /// Smallest `index` such that `prefix_sum(index + 1) >= target`,
+    /// or `len()` if the total sum is below `target`.
+    ///
+    /// Only meaningful when no value is negative (prefix sums are monotone).
+    pub fn lower_bound(&self, target: T) -> usize {
+        let mut step = 1;
+        while step * 2 <= self.len() {
+            step *= 2;
+        }
+
+        let mut pos = 0;
+        let mut rest = target;
+        while step > 0 {
+            if pos + step <= self.len() && self.tree[pos + step] < rest {
+                pos += step;
+                rest = rest - self.tree[pos];
+            }
+            step /= 2;
+        }
+        pos
+    }
+}
@@ -1,3 +1,9 @@
 pub mod binary_search_tree;
 pub mod avl_tree;
 pub mod red_black_tree;
+pub mod trace;
+pub mod fenwick_tree;
+pub mod segment_tree;
+pub mod trie;
+pub mod radix_tree;
+pub mod disjoint_set;
\ No newline at end of file
@@ -0,0 +1,250 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+
+struct RadixNode<V> {
+    // label of the edge leading to this node
+    prefix: String,
+    value: Option<V>,
+    // keyed by the first char of the child's prefix
+    children: BTreeMap<char, RadixNode<V>>,
+}
+
+/// Byte length of the longest common prefix of `a` and `b`, always on a
+/// char boundary of both.
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    let mut len = 0;
+    for (x, y) in a.chars().zip(b.chars()) {
+        if x != y {
+            break;
+        }
+        len += x.len_utf8();
+    }
+    len
+}
+
+fn first_char(s: &str) -> char {
+    s.chars().next().unwrap()
+}
+
+impl<V> RadixNode<V> {
+    fn new(prefix: &str, value: Option<V>) -> Self {
+        RadixNode {
+            prefix: prefix.to_string(),
+            value,
+            children: BTreeMap::new(),
+        }
+    }
+
+    fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        if key.is_empty() {
+            let old = self.value.take();
+            self.value = Some(value);
+            return old;
+        }
+
+        let c = first_char(key);
+        if let Entry::Vacant(e) = self.children.entry(c) {
+            e.insert(RadixNode::new(key, Some(value)));
+            return None;
+        }
+
+        let child = self.children.get_mut(&c).unwrap();
+        let common = common_prefix_len(&child.prefix, key);
+        if common < child.prefix.len() {
+            // split the edge:  self --prefix--> child
+            //             =>   self --common--> middle --rest--> child
+            let rest = child.prefix[common..].to_string();
+            let mut middle = RadixNode::new(&key[..common], None);
+            let mut old_child = ::std::mem::replace(child, RadixNode::new("", None));
+            old_child.prefix = rest;
+            middle.children.insert(first_char(&old_child.prefix), old_child);
+            *child = middle;
+        }
+        child.insert(&key[common..], value)
+    }
+
+    fn remove(&mut self, key: &str) -> Option<V> {
+        if key.is_empty() {
+            return self.value.take();
+        }
+
+        let c = first_char(key);
+        let removed;
+        {
+            let child = self.children.get_mut(&c)?;
+            if !key.starts_with(child.prefix.as_str()) {
+                return None;
+            }
+            removed = child.remove(&key[child.prefix.len()..]);
+            removed.as_ref()?;
+
+            // keep the tree compressed: a valueless node with a single
+            // child is merged with it
+            if child.value.is_none() && child.children.len() == 1 {
+                let k = *child.children.keys().next().unwrap();
+                let mut grandchild = child.children.remove(&k).unwrap();
+                grandchild.prefix = format!("{}{}", child.prefix, grandchild.prefix);
+                *child = grandchild;
+            }
+        }
+        if self.children[&c].value.is_none() && self.children[&c].children.is_empty() {
+            self.children.remove(&c);
+        }
+        removed
+    }
+}
+
+/// Compressed prefix tree (radix tree / patricia trie): chains of nodes with
+/// a single child are merged into one edge labelled by a string.
+///
+/// Edges are only ever split on char boundaries, so multi-byte chars are
+/// never cut in half.
+///
+/// # Example
+/// ```
+/// use algorithm::tree::radix_tree::RadixTree;
+///
+/// let mut routes = RadixTree::new();
+/// routes.insert("/api", "api");
+/// routes.insert("/api/users", "users");
+/// routes.insert("/static", "static");
+///
+/// assert_eq!(routes.longest_prefix_match("/api/users/42"), Some(("/api/users", &"users")));
+/// assert_eq!(routes.longest_prefix_match("/apis"), Some(("/api", &"api")));
+/// assert_eq!(routes.longest_prefix_match("/index"), None);
+/// ```
+pub struct RadixTree<V> {
+    root: RadixNode<V>,
+    len: usize,
+}
+
+impl<V> Default for RadixTree<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> RadixTree<V> {
+    pub fn new() -> Self {
+        RadixTree {
+            root: RadixNode::new("", None),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert `value` under `key`, returning the value it replaced.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let old = self.root.insert(key, value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        let mut node = &self.root;
+        let mut rest = key;
+        while !rest.is_empty() {
+            node = match node.children.get(&first_char(rest)) {
+                Some(child) if rest.starts_with(child.prefix.as_str()) => child,
+                _ => return None,
+            };
+            rest = &rest[node.prefix.len()..];
+        }
+        node.value.as_ref()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let removed = self.root.remove(key);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Iterate `(key, value)` pairs whose key starts with `prefix`,
+    /// in lexicographic order of the chars.
+    pub fn prefix_iter<'a>(&'a self, prefix: &str) -> PrefixIter<'a, V> {
+        let mut stack = Vec::new();
+        let mut node = &self.root;
+        let mut key = String::new();
+        let mut rest = prefix;
+        loop {
+            if rest.is_empty() {
+                stack.push((key, node));
+                break;
+            }
+            let child = match node.children.get(&first_char(rest)) {
+                Some(child) => child,
+                None => break,
+            };
+            if rest.starts_with(child.prefix.as_str()) {
+                rest = &rest[child.prefix.len()..];
+            } else if child.prefix.starts_with(rest) {
+                // the prefix ends in the middle of the edge
+                rest = "";
+            } else {
+                break;
+            }
+            key.push_str(&child.prefix);
+            node = child;
+        }
+        PrefixIter { stack }
+    }
+
+    pub fn iter<'a>(&'a self) -> PrefixIter<'a, V> {
+        self.prefix_iter("")
+    }
+
+    /// The longest stored key which is a prefix of `key`.
+    pub fn longest_prefix_match<'k>(&self, key: &'k str) -> Option<(&'k str, &V)> {
+        let mut node = &self.root;
+        let mut matched = node.value.as_ref().map(|v| (0, v));
+        let mut end = 0;
+        while end < key.len() {
+            let rest = &key[end..];
+            node = match node.children.get(&first_char(rest)) {
+                Some(child) if rest.starts_with(child.prefix.as_str()) => child,
+                _ => break,
+            };
+            end += node.prefix.len();
+            if let Some(ref v) = node.value {
+                matched = Some((end, v));
+            }
+        }
+        matched.map(|(end, v)| (&key[..end], v))
+    }
+}
+
+pub struct PrefixIter<'a, V: 'a> {
+    stack: Vec<(String, &'a RadixNode<V>)>,
+}
+
+impl<'a, V> Iterator for PrefixIter<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((key, node)) = self.stack.pop() {
+            // children pushed in reverse so the smallest one is popped first
+            for child in node.children.values().rev() {
+                self.stack.push((format!("{}{}", key, child.prefix), child));
+            }
+            if let Some(ref v) = node.value {
+                return Some((key, v));
+            }
+        }
+        None
+    }
+}
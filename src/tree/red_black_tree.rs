@@ -1,6 +1,7 @@
 use std::cmp::{max, PartialEq};
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::mem::swap;
+use tree::trace::{Trace, TraceEvent};
 // use utils::rb_tree_helper::{Branch, RBTreeHelper};
 
 enum BalanceAction {
@@ -136,7 +137,10 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
         });
     }
 
-    fn flip_colors(&mut self) {
+    fn flip_colors(&mut self, trace: &mut Trace<T>) {
+        if let Some(v) = self.value() {
+            trace.record(|| TraceEvent::FlipColors { value: v.clone() });
+        }
         // flip both outcoming link color and the incoming link color
         // flip root
         self.flip_color();
@@ -150,7 +154,7 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
         }
     }
 
-    fn insert(&mut self, value: T) -> &mut Self {
+    fn insert(&mut self, value: T, trace: &mut Trace<T>) -> &mut Self {
         // 新插入节点只有两种情况
         // 1. 在 2-节点 插入
         // 2. 在 3-节点 插入
@@ -164,20 +168,23 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
                 )
             }
             RBNode::Node(ref old_value, _, box ref mut l, box ref mut r) => {
+                trace.record(|| TraceEvent::Visit {
+                    value: old_value.clone(),
+                });
                 if value < *old_value {
-                    l.insert(value);
+                    l.insert(value, trace);
                 } else {
-                    r.insert(value);
+                    r.insert(value, trace);
                 }
             }
         }
 
-        self.balance();
+        self.balance(trace);
 
         self
     }
 
-    fn balance(&mut self) {
+    fn balance(&mut self, trace: &mut Trace<T>) {
         let mut ba = BalanceAction::Unknown;
 
         match self {
@@ -194,7 +201,6 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
                 //
                 if r.is_red() && !l.is_red() {
                     ba = BalanceAction::RotateLeft;
-                    // println!("<balance-{}>: rotateLeft", *v);
                 }
 
                 // 【左红 & 左左红】（需要将左红翻转到右边，形成左右均红的下面那种情况，进行处理）
@@ -208,7 +214,6 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
                 //
                 if l.is_red() && l.is_left_red() {
                     ba = BalanceAction::RotateRight;
-                    // println!("<balance>: rotateRight");
                 }
 
                 // 【左右均红】（直接一个翻转分解4-节点即可）
@@ -220,15 +225,14 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
                 //
                 if l.is_red() && r.is_red() {
                     ba = BalanceAction::FlipColors;
-                    // println!("<balance>: flipColors");
                 }
             }
         }
 
         match ba {
-            BalanceAction::RotateLeft => self.rotate_left(),
-            BalanceAction::RotateRight => self.rotate_right(),
-            BalanceAction::FlipColors => self.flip_colors(),
+            BalanceAction::RotateLeft => self.rotate_left(trace),
+            BalanceAction::RotateRight => self.rotate_right(trace),
+            BalanceAction::FlipColors => self.flip_colors(trace),
             _ => {}
         }
     }
@@ -238,13 +242,16 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
     //     self
     // }
 
-    fn remove_min(&mut self) -> &mut Self {
+    fn remove_min(&mut self, trace: &mut Trace<T>) -> &mut Self {
         // 删除模型中，均以根节点为红色作为假设前提！
 
         // 如果当前节点为空（空树：根节点即为空），什么都不要做
         if self.is_empty() {
             return self;
         }
+        trace.record(|| TraceEvent::Visit {
+            value: self.get_value().unwrap(),
+        });
 
         // 无论是【2-节点】还是【3-节点】只要他们的左节点为空，则将当前节点置空即可
         // （因为自己肯定是红色的，删除模型红色假定）
@@ -259,34 +266,29 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
         // 如果 left.left 是红色的话，左节点就是一个3-节点，满足删除条件不用做转换
         // Empty 叶子节点的颜色也是黑色的，满足下列判断条件，走转换流程
         if !self.left().unwrap().is_red() && !self.left().unwrap().left().unwrap().is_red() {
-            self.move_red_left();
+            self.move_red_left(trace);
         }
 
         // 【向左】继续执行
-        self.left_mut().unwrap().remove_min();
+        self.left_mut().unwrap().remove_min(trace);
 
         // 【向上】回溯将4-节点进行拆解恢复
-        self.balance();
+        self.balance(trace);
 
         self
     }
 
-    fn remove_max(&mut self) -> &mut Self {
+    fn remove_max(&mut self, trace: &mut Trace<T>) -> &mut Self {
         // 与 'remove_min' 类似，只是方向向右
         if self.is_empty() {
-            // println!("removing empty");
             return self;
         }
-
-        // let v = self.get_value().unwrap();
-        // println!("cur-node({}):<{:?}>", v, self.color());
+        trace.record(|| TraceEvent::Visit {
+            value: self.get_value().unwrap(),
+        });
 
         if self.left().unwrap().is_red() {
-            self.rotate_right();
-            // println!(
-            //     "after rotate_right: {:?}",
-            //     self.pre_order_with_color().unwrap()
-            // );
+            self.rotate_right(trace);
         }
 
         if self.is_right_empty() {
@@ -296,36 +298,23 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
 
         // 保证右节点为 2-节点
         if !self.right().unwrap().is_red() && !self.right().unwrap().left().unwrap().is_red() {
-            self.move_red_right();
-            // println!(
-            //     "after move_red_right: {:?}",
-            //     self.pre_order_with_color().unwrap()
-            // );
-        }
-
-        self.right_mut().unwrap().remove_max();
-
-        // println!(
-        //     "balancing node({}) => {:?}",
-        //     self.get_value().unwrap(),
-        //     self.pre_order_with_color().unwrap()
-        // );
-        self.balance();
-        // println!(
-        //     "balanced node({}) => {:?}",
-        //     self.get_value().unwrap(),
-        //     self.pre_order_with_color().unwrap()
-        // );
+            self.move_red_right(trace);
+        }
+
+        self.right_mut().unwrap().remove_max(trace);
+
+        self.balance(trace);
 
         self
     }
 
-    fn remove(&mut self, value: &T) -> &mut Self {
-        // println!("[[[delete]]]:{:?}", self.to_vec_with_color().unwrap());
-
+    fn remove(&mut self, value: &T, trace: &mut Trace<T>) -> &mut Self {
         if self.is_empty() {
             return self;
         }
+        trace.record(|| TraceEvent::Visit {
+            value: self.get_value().unwrap(),
+        });
 
         if *value < self.get_value().unwrap() {
             // 左边
@@ -334,14 +323,14 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
                     && self.left().unwrap().left().unwrap().is_red())
             {
                 // RBTreeHelper::hit_branch(Branch::One);
-                self.move_red_left();
+                self.move_red_left(trace);
             }
-            self.left_mut().unwrap().remove(value);
+            self.left_mut().unwrap().remove(value, trace);
         } else {
             // 右边
             if self.left().unwrap().is_red() {
                 // RBTreeHelper::hit_branch(Branch::Two);
-                self.rotate_right();
+                self.rotate_right(trace);
             }
             // 翻转后，在判断右边是否已经找到尽头
             //          |   RED
@@ -358,7 +347,7 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
                     && self.right().unwrap().left().unwrap().is_red())
             {
                 // RBTreeHelper::hit_branch(Branch::Three);
-                self.move_red_right();
+                self.move_red_right(trace);
             }
 
             // 不是叶子节点的情况下
@@ -375,17 +364,15 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
                 }
 
                 // 删除右节点最小值
-                self.right_mut().unwrap().remove_min();
+                self.right_mut().unwrap().remove_min(trace);
             } else {
                 // RBTreeHelper::hit_branch(Branch::Five);
                 // 继续向右查找
-                self.right_mut().unwrap().remove(value);
+                self.right_mut().unwrap().remove(value, trace);
             }
         }
 
-        // println!(">>>balance:{:?}", self.to_vec_with_color().unwrap());
-        self.balance();
-        // println!("<<<balance:{:?}", self.to_vec_with_color().unwrap());
+        self.balance(trace);
 
         self
     }
@@ -400,7 +387,7 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
     /// //   /  \                                       /  \
     /// //  x    y                                     y    z
     ///
-    fn rotate_right(&mut self) {
+    fn rotate_right(&mut self, trace: &mut Trace<T>) {
         let mut x: RBNode<T> = RBNode::Empty;
         let mut y: RBNode<T> = RBNode::Empty;
         let mut z: RBNode<T> = RBNode::Empty;
@@ -412,6 +399,7 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
         match self {
             &mut RBNode::Empty => return,
             &mut RBNode::Node(ref mut v, ref c, box ref mut l, box ref mut r) => {
+                trace.record(|| TraceEvent::RotateRight { value: v.clone() });
                 k1_v = l.get_value().unwrap();
                 k1_c = l.color();
 
@@ -443,7 +431,7 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
     /// //  x   k2        ------------->            k1   z
     /// //      / \                                /  \
     /// //     y   z                              x    y
-    fn rotate_left(&mut self) {
+    fn rotate_left(&mut self, trace: &mut Trace<T>) {
         let mut x: RBNode<T> = RBNode::Empty;
         let mut y: RBNode<T> = RBNode::Empty;
         let mut z: RBNode<T> = RBNode::Empty;
@@ -455,6 +443,7 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
         match self {
             &mut RBNode::Empty => return,
             &mut RBNode::Node(ref mut v, ref c, box ref mut l, box ref mut r) => {
+                trace.record(|| TraceEvent::RotateLeft { value: v.clone() });
                 k2_v = r.get_value().unwrap();
                 k2_c = r.color();
 
@@ -478,7 +467,7 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
         *self = k2;
     }
 
-    fn move_red_left(&mut self) {
+    fn move_red_left(&mut self, trace: &mut Trace<T>) {
         // 这里的所有模型都是以根节点为【红色】节点为假设，原因是因为删除操作开始时，
         // 将根节点颜色修改为红色，而每次 'move_red_left' 的变换总能将左子节点变为红色，
         // 从而保证了红色根节点子树能模型能够向下传递
@@ -486,6 +475,9 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
         if self.is_empty() || self.is_left_empty() || self.is_right_empty() {
             return;
         }
+        trace.record(|| TraceEvent::MoveRedLeft {
+            value: self.get_value().unwrap(),
+        });
         // 这段由【插入】流程保证
         // if !self.is_red() || self.left().unwrap().is_red() || self.right().unwrap().is_red() {
         //     return;
@@ -513,25 +505,28 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
         //                    b   c
         //                       /  <-- RED（根据红黑树性质：红色节点必在左边）
         //                      d
-        self.flip_colors();
+        self.flip_colors(trace);
         if self.right().unwrap().left().unwrap().is_red() {
-            self.right_mut().unwrap().rotate_right();
-            self.rotate_left();
-            self.flip_colors();
+            self.right_mut().unwrap().rotate_right(trace);
+            self.rotate_left(trace);
+            self.flip_colors(trace);
         }
     }
 
-    fn move_red_right(&mut self) {
+    fn move_red_right(&mut self, trace: &mut Trace<T>) {
         if self.is_empty() || self.is_left_empty() || self.is_right_empty() {
             return;
         }
+        trace.record(|| TraceEvent::MoveRedRight {
+            value: self.get_value().unwrap(),
+        });
         // 有红色节点移到右边，没有的话上下做个颜色翻转（变成4-节点满足删除条件）
 
         // 这个步骤处理两种情况：
         // 1) 两边都是2-节点（只需一次翻转就行）
         // 2) 左边3-节点，右边2-节点（做完翻转还得进入下一个if流程，做右转和再次翻转）
         // 具体参考 move_red_left 的注释说明
-        self.flip_colors();
+        self.flip_colors(trace);
 
         // 左节点是3-节点的话需要右转，让右节点变成3-节点（才能删除）
         // 否则的话通过上一步的翻转就可以分解当前的4-节点）
@@ -540,8 +535,8 @@ impl<T: PartialOrd + Clone + Debug + Display> RBNode<T> {
         // RED / \ RED
         //    b   c
         if self.left().unwrap().left().unwrap().is_red() {
-            self.rotate_right();
-            self.flip_colors();
+            self.rotate_right(trace);
+            self.flip_colors(trace);
         }
     }
 
@@ -823,18 +818,41 @@ impl<T: PartialOrd + Clone + Debug + Display> RBTree<T> {
     }
 
     pub fn insert(&mut self, value: T) -> &mut Self {
+        self.insert_with(value, &mut Trace::disabled());
+        self
+    }
+
+    /// Same as `insert`, returning every step taken by the operation.
+    pub fn insert_traced(&mut self, value: T) -> Vec<TraceEvent<T>> {
+        let mut trace = Trace::enabled();
+        self.insert_with(value, &mut trace);
+        trace.into_events()
+    }
+
+    fn insert_with(&mut self, value: T, trace: &mut Trace<T>) {
         if self.root.contains(&value) {
-            return self;
+            return;
         }
 
-        self.root.insert(value);
+        self.root.insert(value, trace);
         self.root.set_color(Color::Black);
-        self
     }
 
     pub fn remove_min(&mut self) -> &mut Self {
+        self.remove_min_with(&mut Trace::disabled());
+        self
+    }
+
+    /// Same as `remove_min`, returning every step taken by the operation.
+    pub fn remove_min_traced(&mut self) -> Vec<TraceEvent<T>> {
+        let mut trace = Trace::enabled();
+        self.remove_min_with(&mut trace);
+        trace.into_events()
+    }
+
+    fn remove_min_with(&mut self, trace: &mut Trace<T>) {
         if self.root.is_empty() {
-            return self;
+            return;
         }
         // 删除模型都以根节点为红色做前提假设，如果两边都为黑色，
         // 则因为首次执行时不满足子树以红色节点作为根节点的假设，
@@ -846,36 +864,55 @@ impl<T: PartialOrd + Clone + Debug + Display> RBTree<T> {
             self.root.set_color(Color::Red);
         }
 
-        self.root.remove_min();
+        self.root.remove_min(trace);
         // 恢复根节点颜色
         self.root.set_color(Color::Black);
-        self
     }
 
     pub fn remove_max(&mut self) -> &mut Self {
+        self.remove_max_with(&mut Trace::disabled());
+        self
+    }
+
+    /// Same as `remove_max`, returning every step taken by the operation.
+    pub fn remove_max_traced(&mut self) -> Vec<TraceEvent<T>> {
+        let mut trace = Trace::enabled();
+        self.remove_max_with(&mut trace);
+        trace.into_events()
+    }
+
+    fn remove_max_with(&mut self, trace: &mut Trace<T>) {
         if !self.root.is_left_red() && !self.root.is_right_red() {
             self.root.set_color(Color::Red);
         }
 
-        self.root.remove_max();
+        self.root.remove_max(trace);
         self.root.set_color(Color::Black);
+    }
 
+    pub fn remove(&mut self, value: &T) -> &mut Self {
+        self.remove_with(value, &mut Trace::disabled());
         self
     }
 
-    pub fn remove(&mut self, value: &T) -> &mut Self {
+    /// Same as `remove`, returning every step taken by the operation.
+    pub fn remove_traced(&mut self, value: &T) -> Vec<TraceEvent<T>> {
+        let mut trace = Trace::enabled();
+        self.remove_with(value, &mut trace);
+        trace.into_events()
+    }
+
+    fn remove_with(&mut self, value: &T, trace: &mut Trace<T>) {
         if !self.root.contains(value) {
-            return self;
+            return;
         }
 
         if !self.root.is_left_red() && !self.root.is_right_red() {
             self.root.set_color(Color::Red);
         }
 
-        self.root.remove(value);
+        self.root.remove(value, trace);
         self.root.set_color(Color::Black);
-
-        self
     }
 
     pub fn pre_order_with_color(&self) -> Option<Vec<RBNodeInfo<T>>> {
@@ -0,0 +1,259 @@
+use std::cmp::PartialOrd;
+use std::marker::PhantomData;
+use std::ops::Add;
+
+/// Associative operation a `SegmentTree` aggregates with.
+///
+/// Range updates add a `delta` to every element of the range, `apply`
+/// tells how such an update changes the aggregate of `len` elements.
+pub trait Operation<T> {
+    fn combine(left: &T, right: &T) -> T;
+    fn apply(value: &T, delta: &T, len: usize) -> T;
+}
+
+pub struct Sum;
+pub struct Min;
+pub struct Max;
+
+impl<T: Copy + Add<Output = T>> Operation<T> for Sum {
+    fn combine(left: &T, right: &T) -> T {
+        *left + *right
+    }
+
+    fn apply(value: &T, delta: &T, len: usize) -> T {
+        // delta * len, by doubling (only `Add` is required)
+        let mut n = len;
+        let mut base = *delta;
+        let mut result = *value;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result + base;
+            }
+            n >>= 1;
+            // the power past the last set bit is unused and may overflow
+            if n > 0 {
+                base = base + base;
+            }
+        }
+        result
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T>> Operation<T> for Min {
+    fn combine(left: &T, right: &T) -> T {
+        if *right < *left {
+            *right
+        } else {
+            *left
+        }
+    }
+
+    fn apply(value: &T, delta: &T, _len: usize) -> T {
+        *value + *delta
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T>> Operation<T> for Max {
+    fn combine(left: &T, right: &T) -> T {
+        if *right > *left {
+            *right
+        } else {
+            *left
+        }
+    }
+
+    fn apply(value: &T, delta: &T, _len: usize) -> T {
+        *value + *delta
+    }
+}
+
+/// Segment tree with lazy propagation.
+///
+/// All ranges are half-open `[start, end)`, empty ranges query to `None`.
+///
+/// # Example
+/// ```
+/// use algorithm::tree::segment_tree::{Min, SegmentTree};
+///
+/// let mut tree: SegmentTree<i32, Min> = SegmentTree::new(&[5, 3, 8, 6]);
+/// assert_eq!(tree.query(0, 4), Some(3));
+/// tree.update(0, 2, 10);
+/// assert_eq!(tree.query(0, 4), Some(6));
+/// ```
+pub struct SegmentTree<T, Op> {
+    len: usize,
+    // node i has children 2i+1 and 2i+2
+    nodes: Vec<Option<T>>,
+    // pending delta not yet pushed down to the children
+    lazy: Vec<Option<T>>,
+    op: PhantomData<Op>,
+}
+
+impl<T: Copy + Add<Output = T>, Op: Operation<T>> SegmentTree<T, Op> {
+    pub fn new(values: &[T]) -> Self {
+        let size = if values.is_empty() {
+            0
+        } else {
+            4 * values.len()
+        };
+        let mut tree = SegmentTree {
+            len: values.len(),
+            nodes: vec![None; size],
+            lazy: vec![None; size],
+            op: PhantomData,
+        };
+        if !values.is_empty() {
+            tree.build(0, 0, values.len(), values);
+        }
+        tree
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Aggregate of the values in `[start, end)`.
+    pub fn query(&self, start: usize, end: usize) -> Option<T> {
+        assert!(start <= end && end <= self.len, "range out of bounds");
+        if start == end {
+            return None;
+        }
+        self.query_node(0, 0, self.len, start, end, None)
+    }
+
+    /// Add `delta` to every value in `[start, end)`.
+    pub fn update(&mut self, start: usize, end: usize, delta: T) {
+        assert!(start <= end && end <= self.len, "range out of bounds");
+        if start == end {
+            return;
+        }
+        let len = self.len;
+        self.update_node(0, 0, len, start, end, &delta);
+    }
+
+    /// Replace the value at `index`.
+    pub fn set(&mut self, index: usize, value: T) {
+        assert!(index < self.len, "index out of range");
+        let len = self.len;
+        self.set_node(0, 0, len, index, value);
+    }
+
+    // ---------------------------------------------------------------------------------
+    //                              helper methods
+    // ---------------------------------------------------------------------------------
+    fn build(&mut self, node: usize, lo: usize, hi: usize, values: &[T]) {
+        if hi - lo == 1 {
+            self.nodes[node] = Some(values[lo]);
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        self.build(2 * node + 1, lo, mid, values);
+        self.build(2 * node + 2, mid, hi, values);
+        self.pull(node);
+    }
+
+    fn pull(&mut self, node: usize) {
+        self.nodes[node] = match (self.nodes[2 * node + 1], self.nodes[2 * node + 2]) {
+            (Some(l), Some(r)) => Some(Op::combine(&l, &r)),
+            (l, None) => l,
+            (None, r) => r,
+        };
+    }
+
+    fn apply_node(&mut self, node: usize, len: usize, delta: &T) {
+        if let Some(v) = self.nodes[node] {
+            self.nodes[node] = Some(Op::apply(&v, delta, len));
+        }
+        self.lazy[node] = match self.lazy[node] {
+            Some(pending) => Some(pending + *delta),
+            None => Some(*delta),
+        };
+    }
+
+    fn push(&mut self, node: usize, lo: usize, hi: usize) {
+        if let Some(delta) = self.lazy[node].take() {
+            let mid = lo + (hi - lo) / 2;
+            self.apply_node(2 * node + 1, mid - lo, &delta);
+            self.apply_node(2 * node + 2, hi - mid, &delta);
+        }
+    }
+
+    /// `pending` is the sum of the deltas held by the ancestors, which are
+    /// applied on the way back instead of being pushed down, so querying
+    /// does not need to mutate the tree.
+    fn query_node(
+        &self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        start: usize,
+        end: usize,
+        pending: Option<T>,
+    ) -> Option<T> {
+        if end <= lo || hi <= start {
+            return None;
+        }
+        if start <= lo && hi <= end {
+            return match (self.nodes[node], pending) {
+                (Some(v), Some(delta)) => Some(Op::apply(&v, &delta, hi - lo)),
+                (v, _) => v,
+            };
+        }
+        let pending = match (pending, self.lazy[node]) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, None) => a,
+            (None, b) => b,
+        };
+        let mid = lo + (hi - lo) / 2;
+        let l = self.query_node(2 * node + 1, lo, mid, start, end, pending);
+        let r = self.query_node(2 * node + 2, mid, hi, start, end, pending);
+        match (l, r) {
+            (Some(l), Some(r)) => Some(Op::combine(&l, &r)),
+            (l, None) => l,
+            (None, r) => r,
+        }
+    }
+
+    fn update_node(
+        &mut self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        start: usize,
+        end: usize,
+        delta: &T,
+    ) {
+        if end <= lo || hi <= start {
+            return;
+        }
+        if start <= lo && hi <= end {
+            self.apply_node(node, hi - lo, delta);
+            return;
+        }
+        self.push(node, lo, hi);
+        let mid = lo + (hi - lo) / 2;
+        self.update_node(2 * node + 1, lo, mid, start, end, delta);
+        self.update_node(2 * node + 2, mid, hi, start, end, delta);
+        self.pull(node);
+    }
+
+    fn set_node(&mut self, node: usize, lo: usize, hi: usize, index: usize, value: T) {
+        if hi - lo == 1 {
+            self.nodes[node] = Some(value);
+            self.lazy[node] = None;
+            return;
+        }
+        self.push(node, lo, hi);
+        let mid = lo + (hi - lo) / 2;
+        if index < mid {
+            self.set_node(2 * node + 1, lo, mid, index, value);
+        } else {
+            self.set_node(2 * node + 2, mid, hi, index, value);
+        }
+        self.pull(node);
+    }
+}
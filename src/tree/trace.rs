@@ -0,0 +1,79 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// A single step performed by a tree operation.
+///
+/// The value carried by every event is the value of the (local) root node
+/// the step happened on, so a sequence of events can be serialized to JSON
+/// and replayed step by step next to a drawing of the tree.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum TraceEvent<T> {
+    /// The operation passed through the node.
+    Visit { value: T },
+    /// The node's right child was rotated up to replace it.
+    RotateLeft { value: T },
+    /// The node's left child was rotated up to replace it.
+    RotateRight { value: T },
+    /// Colors of the node and its two children were flipped (red-black tree).
+    FlipColors { value: T },
+    /// A red link was moved to the left child (red-black tree removal).
+    MoveRedLeft { value: T },
+    /// A red link was moved to the right child (red-black tree removal).
+    MoveRedRight { value: T },
+    /// The node's height changed (AVL tree).
+    UpdateHeight { value: T, height: isize },
+}
+
+impl<T: Display> Display for TraceEvent<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            TraceEvent::Visit { value } => write!(f, "visit {}", value),
+            TraceEvent::RotateLeft { value } => write!(f, "rotate left at {}", value),
+            TraceEvent::RotateRight { value } => write!(f, "rotate right at {}", value),
+            TraceEvent::FlipColors { value } => write!(f, "flip colors at {}", value),
+            TraceEvent::MoveRedLeft { value } => write!(f, "move red left at {}", value),
+            TraceEvent::MoveRedRight { value } => write!(f, "move red right at {}", value),
+            TraceEvent::UpdateHeight { value, height } => {
+                write!(f, "height of {} -> {}", value, height)
+            }
+        }
+    }
+}
+
+/// Collector threaded through the recursive tree operations.
+///
+/// A disabled trace simply drops every recorded event.
+pub struct Trace<T> {
+    events: Option<Vec<TraceEvent<T>>>,
+}
+
+impl<T> Trace<T> {
+    pub fn disabled() -> Self {
+        Trace { events: None }
+    }
+
+    pub fn enabled() -> Self {
+        Trace {
+            events: Some(Vec::new()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.events.is_some()
+    }
+
+    /// Record the event `event` builds; it is not called when the trace
+    /// is disabled, so building the event (e.g. cloning the value) costs
+    /// nothing then.
+    pub fn record<F>(&mut self, event: F)
+    where
+        F: FnOnce() -> TraceEvent<T>,
+    {
+        if let Some(ref mut events) = self.events {
+            events.push(event());
+        }
+    }
+
+    pub fn into_events(self) -> Vec<TraceEvent<T>> {
+        self.events.unwrap_or_default()
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+
+struct TrieNode<V> {
+    value: Option<V>,
+    children: BTreeMap<char, TrieNode<V>>,
+}
+
+impl<V> TrieNode<V> {
+    fn new() -> Self {
+        TrieNode {
+            value: None,
+            children: BTreeMap::new(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value.is_none() && self.children.is_empty()
+    }
+
+    fn remove(&mut self, mut chars: ::std::str::Chars) -> Option<V> {
+        match chars.next() {
+            None => self.value.take(),
+            Some(c) => {
+                let child = self.children.get_mut(&c)?;
+                let removed = child.remove(chars);
+                let prune = child.is_empty();
+                // drop the branch that does not lead to any value anymore
+                if prune {
+                    self.children.remove(&c);
+                }
+                removed
+            }
+        }
+    }
+}
+
+/// Prefix tree keyed by the `char`s of a string, one node per char, so
+/// multi-byte (e.g. Chinese) keys are handled the same as ASCII ones.
+///
+/// # Example
+/// ```
+/// use algorithm::tree::trie::Trie;
+///
+/// let mut trie = Trie::new();
+/// trie.insert("中文", 1);
+/// trie.insert("中文测试", 2);
+/// trie.insert("中国", 3);
+///
+/// let words: Vec<String> = trie.prefix_iter("中文").map(|(k, _)| k).collect();
+/// assert_eq!(words, vec!["中文", "中文测试"]);
+/// assert_eq!(trie.longest_prefix_match("中文测试用例"), Some(("中文测试", &2)));
+/// ```
+pub struct Trie<V> {
+    root: TrieNode<V>,
+    len: usize,
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> Trie<V> {
+    pub fn new() -> Self {
+        Trie {
+            root: TrieNode::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert `value` under `key`, returning the value it replaced.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.entry(c).or_insert_with(TrieNode::new);
+        }
+
+        let old = node.value.take();
+        node.value = Some(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.find(key).and_then(|node| node.value.as_ref())
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        let mut node = &mut self.root;
+        for c in key.chars() {
+            node = node.children.get_mut(&c)?;
+        }
+        node.value.as_mut()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let removed = self.root.remove(key.chars());
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Iterate `(key, value)` pairs whose key starts with `prefix`,
+    /// in lexicographic order of the chars.
+    pub fn prefix_iter<'a>(&'a self, prefix: &str) -> PrefixIter<'a, V> {
+        let mut stack = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            stack.push((prefix.to_string(), node));
+        }
+        PrefixIter { stack }
+    }
+
+    pub fn iter<'a>(&'a self) -> PrefixIter<'a, V> {
+        self.prefix_iter("")
+    }
+
+    /// The longest stored key which is a prefix of `key`.
+    pub fn longest_prefix_match<'k>(&self, key: &'k str) -> Option<(&'k str, &V)> {
+        let mut node = &self.root;
+        let mut matched = node.value.as_ref().map(|v| (0, v));
+        for (i, c) in key.char_indices() {
+            node = match node.children.get(&c) {
+                Some(child) => child,
+                None => break,
+            };
+            if let Some(ref v) = node.value {
+                matched = Some((i + c.len_utf8(), v));
+            }
+        }
+        matched.map(|(end, v)| (&key[..end], v))
+    }
+
+    fn find(&self, key: &str) -> Option<&TrieNode<V>> {
+        let mut node = &self.root;
+        for c in key.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+pub struct PrefixIter<'a, V: 'a> {
+    stack: Vec<(String, &'a TrieNode<V>)>,
+}
+
+impl<'a, V> Iterator for PrefixIter<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((key, node)) = self.stack.pop() {
+            // children pushed in reverse so the smallest one is popped first
+            for (c, child) in node.children.iter().rev() {
+                let mut child_key = key.clone();
+                child_key.push(*c);
+                self.stack.push((child_key, child));
+            }
+            if let Some(ref v) = node.value {
+                return Some((key, v));
+            }
+        }
+        None
+    }
+}
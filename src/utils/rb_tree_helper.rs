@@ -1,10 +1,9 @@
-extern crate serde;
-extern crate serde_json;
-
 use std::fs::{remove_file, File};
 use std::path::Path;
 
-extern crate rand;
+use serde_json;
+
+use rand;
 use rand::distributions::{IndependentSample, Range};
 
 use utils::system::pause;
@@ -0,0 +1,107 @@
+//! Fixtures shared by the integration tests; each test uses only some.
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use rand::{self, Rng};
+
+/// Element counting the comparisons made on it.
+#[derive(Clone, Copy, Debug)]
+pub struct Probe<'a> {
+    pub value: u32,
+    pub probes: &'a Cell<usize>,
+}
+
+impl<'a> PartialEq for Probe<'a> {
+    fn eq(&self, other: &Probe<'a>) -> bool {
+        self.probes.set(self.probes.get() + 1);
+        self.value == other.value
+    }
+}
+
+impl<'a> PartialOrd for Probe<'a> {
+    fn partial_cmp(&self, other: &Probe<'a>) -> Option<Ordering> {
+        self.probes.set(self.probes.get() + 1);
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+/// For `interpolation_search`, which does not count the conversion.
+impl<'a> From<Probe<'a>> for f64 {
+    fn from(probe: Probe<'a>) -> f64 {
+        f64::from(probe.value)
+    }
+}
+
+/// A source of `Probe`s, wrapped once and searched many times.
+pub struct Probed<'a> {
+    source: Vec<Probe<'a>>,
+    probes: &'a Cell<usize>,
+}
+
+impl<'a> Probed<'a> {
+    pub fn new(source: &[u32], probes: &'a Cell<usize>) -> Self {
+        Probed {
+            source: source
+                .iter()
+                .map(|&value| Probe { value, probes })
+                .collect(),
+            probes,
+        }
+    }
+
+    /// Comparisons made by `search` looking for `target`.
+    pub fn count<F>(&self, target: u32, search: F) -> usize
+    where
+        F: Fn(&[Probe<'a>], &Probe<'a>) -> bool,
+    {
+        self.probes.set(0);
+        search(
+            &self.source,
+            &Probe {
+                value: target,
+                probes: self.probes,
+            },
+        );
+        self.probes.get()
+    }
+}
+
+/// Compared by `key` only, `payload` records the original position, to
+/// check sorts for stability.
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub key: u32,
+    pub payload: usize,
+}
+
+impl PartialEq for Record {
+    fn eq(&self, other: &Record) -> bool {
+        self.key == other.key
+    }
+}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Record) -> Option<Ordering> {
+        self.key.partial_cmp(&other.key)
+    }
+}
+
+/// `len` records with random keys below `keys`, numbered in order.
+pub fn records(len: usize, keys: u32) -> Vec<Record> {
+    let mut rng = rand::thread_rng();
+    (0..len)
+        .map(|i| Record {
+            key: rng.gen_range(0, keys),
+            payload: i,
+        })
+        .collect()
+}
+
+/// Sorted by key, and records with equal keys in their original order.
+pub fn assert_stable(source: &[Record]) {
+    for w in source.windows(2) {
+        assert!(w[0].key < w[1].key || (w[0].key == w[1].key && w[0].payload < w[1].payload));
+    }
+}
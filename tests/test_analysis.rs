@@ -0,0 +1,96 @@
+extern crate algorithm;
+
+use algorithm::sort::analysis::{antiqsort, measure, sorts, Input, Measurement, Report};
+use algorithm::sort::insertion::insertion_sort;
+use algorithm::sort::intro::intro_sort;
+use algorithm::sort::merge::merge_sort;
+use algorithm::sort::quick::quick_sort;
+
+#[test]
+fn test_measure() {
+    let sorted: Vec<u64> = (0..100).collect();
+    let (m, result) = measure(&sorted, insertion_sort);
+    assert_eq!(result, sorted);
+    assert_eq!(
+        m,
+        Measurement {
+            comparisons: 99,
+            relocations: 0,
+        }
+    );
+
+    let (m, result) = measure(&[2, 1], quick_sort);
+    assert_eq!(result, vec![1, 2]);
+    assert_eq!(m.comparisons, 1);
+    assert_eq!(m.relocations, 2);
+
+    // merge sort copies the left run out and back
+    let (m, _) = measure(&[2, 1], merge_sort);
+    assert!(m.relocations >= 2);
+}
+
+#[test]
+fn test_every_sort_sorts_every_input() {
+    for input in Input::all() {
+        let source = input.generate(300);
+        let mut exp = source.clone();
+        exp.sort();
+        for (name, sort) in sorts() {
+            let (_, result) = measure(&source, sort);
+            assert!(result == exp, "{} on {} input", name, input.name());
+        }
+    }
+}
+
+#[test]
+fn test_generators() {
+    let len = 100;
+    for input in Input::all() {
+        assert_eq!(input.generate(len).len(), len);
+        assert!(input.generate(0).is_empty());
+    }
+    assert_eq!(Input::Sorted.generate(4), vec![0, 1, 2, 3]);
+    assert_eq!(Input::Reversed.generate(4), vec![3, 2, 1, 0]);
+    assert_eq!(Input::OrganPipe.generate(6), vec![0, 1, 2, 2, 1, 0]);
+    assert_eq!(Input::Sawtooth.generate(9), vec![0, 1, 2, 0, 1, 2, 0, 1, 2]);
+
+    let mut few = Input::FewUnique.generate(1000);
+    few.sort();
+    few.dedup();
+    assert!(few.len() <= 8);
+}
+
+#[test]
+fn test_antiqsort() {
+    let len = 2000;
+    let killer = antiqsort(len);
+    assert_eq!(killer.len(), len);
+    assert!(killer.iter().all(|&v| v <= len as u64));
+
+    let (random, _) = measure(&Input::Random.generate(len), quick_sort);
+    let (adversary, result) = measure(&killer, quick_sort);
+    assert!(result.windows(2).all(|w| w[0] <= w[1]));
+    // quadratic instead of n log n
+    assert!(adversary.comparisons > 10 * random.comparisons);
+
+    // intro sort bounds the damage
+    let (intro, _) = measure(&killer, intro_sort);
+    assert!(intro.comparisons < adversary.comparisons / 2);
+}
+
+#[test]
+fn test_report() {
+    let report = Report::all(50);
+    assert_eq!(report.rows().len(), Input::all().len() * sorts().len());
+    assert!(report.rows().iter().all(|row| row.len == 50));
+
+    let mut report = Report::new();
+    report.measure("quick_sort", quick_sort, Input::Sorted, 10);
+    report.add("custom", "none", 0, Measurement::default());
+    let table = report.to_string();
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("algorithm"));
+    assert!(lines[1].starts_with("quick_sort"));
+    assert!(lines[2].starts_with("custom"));
+}
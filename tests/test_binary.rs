@@ -1,5 +1,12 @@
 extern crate algorithm;
-use algorithm::search::binary::binary_search;
+extern crate rand;
+
+use algorithm::search::binary::{
+    binary_search, binary_search_by, binary_search_by_key, bisect, bisect_root, equal_range,
+    find_largest, find_smallest, golden_section_search, lower_bound, partition_point,
+    ternary_search, upper_bound,
+};
+use rand::Rng;
 
 #[test]
 fn test_array_found() {
@@ -17,3 +24,153 @@ fn test_array_not_found() {
     assert_eq!(is_found, false);
 }
 
+#[test]
+fn test_empty() {
+    let source: Vec<i32> = Vec::new();
+    assert!(!binary_search(&source, &1));
+    assert_eq!(lower_bound(&source, &1), 0);
+    assert_eq!(upper_bound(&source, &1), 0);
+    assert_eq!(equal_range(&source, &1), 0..0);
+    assert_eq!(binary_search_by(&source, |x| x.cmp(&1)), Err(0));
+    assert_eq!(partition_point(&source, |_| true), 0);
+}
+
+#[test]
+fn test_below_and_above() {
+    let source = vec![1, 2, 3, 4, 5];
+    assert!(!binary_search(&source, &0));
+    assert_eq!(binary_search_by(&source, |x| x.cmp(&0)), Err(0));
+    assert_eq!(binary_search_by(&source, |x| x.cmp(&6)), Err(5));
+    assert!(binary_search(&[1], &1));
+}
+
+#[test]
+fn test_bounds() {
+    let source = [1, 2, 2, 2, 4, 4, 7];
+    assert_eq!(lower_bound(&source, &2), 1);
+    assert_eq!(upper_bound(&source, &2), 4);
+    assert_eq!(lower_bound(&source, &3), 4);
+    assert_eq!(upper_bound(&source, &3), 4);
+    assert_eq!(lower_bound(&source, &0), 0);
+    assert_eq!(upper_bound(&source, &7), 7);
+    assert_eq!(equal_range(&source, &4), 4..6);
+    assert_eq!(equal_range(&source, &5), 6..6);
+    assert_eq!(&source[equal_range(&source, &2)], &[2, 2, 2]);
+}
+
+#[test]
+fn test_random_against_linear() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let len = rng.gen_range(0, 50);
+        let mut source: Vec<i32> = (0..len).map(|_| rng.gen_range(0, 20)).collect();
+        source.sort();
+        for target in -1..21 {
+            let lo = source.iter().take_while(|&&x| x < target).count();
+            let hi = source.iter().take_while(|&&x| x <= target).count();
+            assert_eq!(lower_bound(&source, &target), lo);
+            assert_eq!(upper_bound(&source, &target), hi);
+            assert_eq!(equal_range(&source, &target), lo..hi);
+            assert_eq!(binary_search(&source, &target), lo < hi);
+
+            let exp = if lo < hi { Ok(lo) } else { Err(lo) };
+            assert_eq!(binary_search_by(&source, |x| x.cmp(&target)), exp);
+            assert_eq!(partition_point(&source, |&x| x < target), lo);
+        }
+    }
+}
+
+#[test]
+fn test_by_key() {
+    let source = [(1, 'a'), (3, 'b'), (3, 'c'), (8, 'd')];
+    assert_eq!(binary_search_by_key(&source, &3, |&(k, _)| k), Ok(1));
+    assert_eq!(binary_search_by_key(&source, &8, |&(k, _)| k), Ok(3));
+    assert_eq!(binary_search_by_key(&source, &5, |&(k, _)| k), Err(3));
+    assert_eq!(binary_search_by_key(&source, &9, |&(k, _)| k), Err(4));
+}
+
+#[test]
+fn test_find_smallest() {
+    assert_eq!(find_smallest(0..100, |x| x >= 42), Some(42));
+    assert_eq!(find_smallest(0..100, |_| true), Some(0));
+    assert_eq!(find_smallest(0..100, |_| false), None);
+    assert_eq!(find_smallest(5..5, |_| true), None);
+    assert_eq!(find_smallest(-50i32..50, |x| x * 3 >= -10), Some(-3));
+
+    // the whole domain, without overflowing the midpoint
+    assert_eq!(
+        find_smallest(i64::MIN..i64::MAX, |x| x >= 7),
+        Some(7)
+    );
+    assert_eq!(
+        find_smallest(0..u64::MAX, |x| x > u64::MAX / 3),
+        Some(u64::MAX / 3 + 1)
+    );
+    assert_eq!(
+        find_smallest(i8::MIN..i8::MAX, |x| x >= -128),
+        Some(-128)
+    );
+
+    let mut calls = 0;
+    find_smallest(0..1 << 20, |x| {
+        calls += 1;
+        x >= 12345
+    });
+    assert!(calls <= 21);
+}
+
+#[test]
+fn test_find_largest() {
+    assert_eq!(find_largest(0..100, |x| x <= 42), Some(42));
+    assert_eq!(find_largest(0..100, |_| true), Some(99));
+    assert_eq!(find_largest(0..100, |_| false), None);
+    assert_eq!(find_largest(3u8..3, |_| true), None);
+    assert_eq!(find_largest(-10..10, |x| x < -9), Some(-10));
+}
+
+#[test]
+fn test_bisect() {
+    let x = bisect(0.0..10.0, 1e-9, 1000, |x| x * x * x >= 10.0);
+    assert!((x - 10f64.cbrt()).abs() < 1e-9);
+    assert!(x * x * x >= 10.0);
+
+    // the iteration limit stops it early
+    let mut calls = 0;
+    let x = bisect(0.0..1.0, 0.0, 10, |x| {
+        calls += 1;
+        x >= 0.3
+    });
+    assert_eq!(calls, 10);
+    assert!((x - 0.3).abs() < 1.0 / 1024.0);
+
+    let root = bisect_root(-3.0..0.0, 1e-12, 200, |x| x * x - 2.0).unwrap();
+    assert!((root + 2f64.sqrt()).abs() < 1e-9);
+    assert_eq!(bisect_root(0.0..1.0, 1e-12, 200, |x| x), Some(0.0));
+    assert_eq!(bisect_root(2.0..3.0, 1e-12, 200, |x| x * x - 2.0), None);
+}
+
+#[test]
+fn test_unimodal() {
+    let f = |x: f64| (x - 1.5).abs() + 2.0;
+    let x = ternary_search(-100.0..100.0, 1e-9, 500, f);
+    assert!((x - 1.5).abs() < 1e-6);
+    let x = golden_section_search(-100.0..100.0, 1e-9, 500, f);
+    assert!((x - 1.5).abs() < 1e-6);
+
+    // maximum of a concave function
+    let x = golden_section_search(0.0..3.0, 1e-9, 500, |x| -x.sin());
+    assert!((x - ::std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+
+    // golden-section evaluates f about once per step, ternary twice
+    let mut ternary_calls = 0;
+    ternary_search(0.0..1.0, 1e-8, 500, |x| {
+        ternary_calls += 1;
+        (x - 0.7) * (x - 0.7)
+    });
+    let mut golden_calls = 0;
+    golden_section_search(0.0..1.0, 1e-8, 500, |x| {
+        golden_calls += 1;
+        (x - 0.7) * (x - 0.7)
+    });
+    assert!(golden_calls < ternary_calls);
+}
@@ -0,0 +1,56 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::heap::binary_heap::BinaryHeap;
+use rand::Rng;
+
+#[test]
+fn test_min_heap() {
+    let mut heap = BinaryHeap::new();
+    for v in [5, 3, 8, 1, 9, 2] {
+        heap.push(v);
+    }
+    assert_eq!(heap.len(), 6);
+    assert_eq!(heap.peek(), Some(&1));
+    assert_eq!(heap.into_sorted_vec(), vec![1, 2, 3, 5, 8, 9]);
+}
+
+#[test]
+fn test_max_heap_by_comparator() {
+    let mut heap = BinaryHeap::with_comparator(3, |a: &i32, b: &i32| b.cmp(a));
+    for v in [5, 3, 8, 1, 9, 2] {
+        heap.push(v);
+    }
+    assert_eq!(heap.pop(), Some(9));
+    assert_eq!(heap.pop(), Some(8));
+    assert_eq!(heap.pop(), Some(5));
+}
+
+#[test]
+fn test_pop_empty() {
+    let mut heap: BinaryHeap<i32> = BinaryHeap::new();
+    assert!(heap.is_empty());
+    assert_eq!(heap.peek(), None);
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn test_from_vec() {
+    let heap = BinaryHeap::from_vec(4, vec![7, 2, 9, 4, 4, 1, 8], |a: &i32, b: &i32| a.cmp(b));
+    assert_eq!(heap.arity(), 4);
+    assert_eq!(heap.into_sorted_vec(), vec![1, 2, 4, 4, 7, 8, 9]);
+}
+
+#[test]
+fn test_random_arities() {
+    let mut rng = rand::thread_rng();
+    for arity in 2..6 {
+        let mut source: Vec<u32> = (0..200).map(|_| rng.gen_range(0, 50)).collect();
+        let mut heap = BinaryHeap::with_arity(arity);
+        for v in &source {
+            heap.push(*v);
+        }
+        source.sort();
+        assert_eq!(heap.into_sorted_vec(), source);
+    }
+}
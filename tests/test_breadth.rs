@@ -2,8 +2,14 @@ extern crate algorithm;
 
 use std::collections::HashMap;
 
+use algorithm::graph::adjacency::Graph;
 use algorithm::search::breadth::*;
 
+fn ends_with_m(name: &&str) -> bool {
+    let chars: Vec<char> = name.chars().collect();
+    *chars.last().unwrap() == 'm'
+}
+
 #[test]
 fn breadth_search_generic_array_found() {
     let mut graph = HashMap::new();
@@ -17,31 +23,33 @@ fn breadth_search_generic_array_found() {
 
     let starter = "you";
 
-    let found = breadth_search_generic(&mut graph, &starter, |name| {
-        let chars: Vec<char> = name.chars().collect();
-        *chars.last().unwrap() == 'm'
-    });
-    assert_eq!(found, true);
+    let found = breadth_search(&graph, &starter, ends_with_m);
+    assert_eq!(found.found, Some("thom"));
+    assert_eq!(found.path.len(), 4);
+    assert_eq!(found.path[0], "you");
+    assert_eq!(found.path[2], "peggy");
+    assert_eq!(found.path[3], "thom");
 }
 
 #[test]
 fn test_breadth_search_new_array_not_found() {
-    let mut graph = HashMap::new();
-    graph.insert("you", vec!["alice", "bob", "claire"]);
-    graph.insert("bob", vec!["anuj", "peggy"]);
-    graph.insert("alice", vec!["peggy"]);
-    graph.insert("anuj", vec![]);
-    graph.insert("peggy", vec![]);
-    graph.insert("thom", vec![]);
-    graph.insert("jony", vec![]);
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+    graph.insert(
+        "you".to_string(),
+        vec!["alice".to_string(), "bob".to_string()],
+    );
+    graph.insert(
+        "bob".to_string(),
+        vec!["anuj".to_string(), "peggy".to_string()],
+    );
+    graph.insert("thom".to_string(), vec![]);
 
-    let starter = "you";
+    let starter = "you".to_string();
 
-    let found = breadth_search(&mut graph, starter, |name| {
-        let chars: Vec<char> = name.chars().collect();
-        *chars.last().unwrap() == 'm'
-    });
-    assert_eq!(found, false);
+    let found = breadth_search(&graph, &starter, |name| name.ends_with('m'));
+    assert_eq!(found.found, None);
+    assert!(found.path.is_empty());
+    assert_eq!(found.distances.len(), 5);
 }
 
 #[test]
@@ -57,11 +65,23 @@ fn test_breadth_search_array_not_found() {
 
     let starter = "you";
 
-    let found = breadth_search(&mut graph, starter, |name| {
-        let chars: Vec<char> = name.chars().collect();
-        *chars.last().unwrap() == 'm'
-    });
-    assert_eq!(found, false);
+    let found = breadth_search(&graph, &starter, ends_with_m);
+    assert_eq!(found.found, None);
+
+    // everything reachable was walked, "thom" and "jony" are not
+    let mut reached: Vec<(&str, usize)> = found.distances.into_iter().collect();
+    reached.sort();
+    assert_eq!(
+        reached,
+        vec![
+            ("alice", 1),
+            ("anuj", 2),
+            ("bob", 1),
+            ("claire", 1),
+            ("peggy", 2),
+            ("you", 0)
+        ]
+    );
 }
 
 #[test]
@@ -77,9 +97,75 @@ fn test_breadth_search_array_found() {
 
     let starter = "you";
 
-    let found = breadth_search(&mut graph, starter, |name| {
-        let chars: Vec<char> = name.chars().collect();
-        *chars.last().unwrap() == 'm'
-    });
-    assert_eq!(found, true);
+    let found = breadth_search(&graph, &starter, ends_with_m);
+    assert_eq!(found.found, Some("thom"));
+    assert_eq!(found.distances["thom"], 3);
+}
+
+#[test]
+fn test_breadth_search_closest() {
+    // a long way round is found first by a depth-first walk
+    let mut graph = HashMap::new();
+    graph.insert(0, vec![1, 5]);
+    graph.insert(1, vec![2]);
+    graph.insert(2, vec![3]);
+    graph.insert(3, vec![4]);
+    graph.insert(4, vec![5]);
+    graph.insert(5, vec![6]);
+
+    let found = breadth_search(&graph, &0, |&n| n == 6);
+    assert_eq!(found.path, vec![0, 5, 6]);
+    assert_eq!(found.distances[&5], 1);
+
+    // the starter is checked too
+    let found = breadth_search(&graph, &0, |&n| n == 0);
+    assert_eq!(found.path, vec![0]);
+}
+
+#[test]
+fn test_breadth_search_missing_starter() {
+    let mut graph = HashMap::new();
+    graph.insert(1, vec![2]);
+
+    let found = breadth_search(&graph, &9, |&n| n == 2);
+    assert_eq!(found.found, None);
+    assert_eq!(found.distances.len(), 1);
+}
+
+#[test]
+fn test_breadth_search_graph() {
+    let mut graph = Graph::undirected();
+    graph.add_edge("a", "b", ());
+    graph.add_edge("b", "c", ());
+    graph.add_edge("c", "d", ());
+    graph.add_edge("a", "d", ());
+
+    // undirected: "d" is one step back from "a"
+    let found = breadth_search(&graph, &"a", |&n| n == "d");
+    assert_eq!(found.path, vec!["a", "d"]);
+
+    let found = breadth_search(&graph, &"b", |_| false);
+    assert_eq!(found.distances["d"], 2);
+}
+
+#[test]
+fn test_bfs_iter() {
+    let mut graph = HashMap::new();
+    graph.insert(0, vec![1, 2]);
+    graph.insert(1, vec![3]);
+    graph.insert(2, vec![3, 4]);
+    graph.insert(4, vec![0, 5]);
+    graph.insert(6, vec![0]);
+
+    let order: Vec<u32> = bfs_iter(&graph, 0).collect();
+    assert_eq!(order, vec![0, 1, 2, 3, 4, 5]);
+
+    // the distances never decrease, and match breadth_search
+    let distances = breadth_search(&graph, &0, |_| false).distances;
+    for pair in order.windows(2) {
+        assert!(distances[&pair[0]] <= distances[&pair[1]]);
+    }
+    assert_eq!(order.len(), distances.len());
+
+    assert_eq!(bfs_iter(&graph, 5).collect::<Vec<_>>(), vec![5]);
 }
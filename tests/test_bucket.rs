@@ -0,0 +1,47 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::sort::bucket::bucket_sort;
+use rand::Rng;
+
+#[test]
+fn test_bucket_sort_uniform() {
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<f64> = (0..5000).map(|_| rng.gen()).collect();
+    let mut exp = source.clone();
+    exp.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    bucket_sort(&mut source);
+    assert_eq!(source, exp);
+}
+
+#[test]
+fn test_bucket_sort_skewed() {
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<f32> = (0..1000)
+        .map(|_| {
+            let x: f32 = rng.gen_range(-1.0, 1.0);
+            x * x * x * 1000.0
+        })
+        .collect();
+    source.push(-0.0);
+    source.push(0.0);
+    let mut exp = source.clone();
+    exp.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    bucket_sort(&mut source);
+    assert_eq!(source, exp);
+}
+
+#[test]
+fn test_bucket_sort_edge_cases() {
+    let mut source: Vec<f64> = Vec::new();
+    bucket_sort(&mut source);
+    assert!(source.is_empty());
+
+    let mut source = vec![2.5];
+    bucket_sort(&mut source);
+    assert_eq!(source, vec![2.5]);
+
+    let mut source = vec![1.0, 1.0, 1.0];
+    bucket_sort(&mut source);
+    assert_eq!(source, vec![1.0, 1.0, 1.0]);
+}
@@ -0,0 +1,97 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::sort::counting::counting_sort;
+use algorithm::sort::radix::RadixKey;
+use rand::Rng;
+
+/// Sorted by `key` only, `payload` records the original position.
+#[derive(Clone, Debug, PartialEq)]
+struct Record {
+    key: i32,
+    payload: usize,
+}
+
+impl RadixKey for Record {
+    fn key_len(&self) -> usize {
+        self.key.key_len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self.key.key_byte(index)
+    }
+}
+
+#[test]
+fn test_counting_sort() {
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<i64> = (0..5000).map(|_| rng.gen_range(-100, 100)).collect();
+    let mut exp = source.clone();
+    exp.sort();
+    counting_sort(&mut source);
+    assert_eq!(source, exp);
+
+    let mut source: Vec<u32> = Vec::new();
+    counting_sort(&mut source);
+    assert!(source.is_empty());
+
+    let mut source = vec![7u64; 10];
+    counting_sort(&mut source);
+    assert_eq!(source, vec![7u64; 10]);
+}
+
+#[test]
+fn test_counting_sort_stable() {
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<Record> = (0..1000)
+        .map(|i| Record {
+            key: rng.gen_range(-5, 5),
+            payload: i,
+        })
+        .collect();
+    let mut exp = source.clone();
+    exp.sort_by_key(|r| r.key);
+    counting_sort(&mut source);
+    assert_eq!(source, exp);
+}
+
+#[test]
+fn test_counting_sort_wide_range() {
+    let mut source = vec![u32::MAX, 0, 7, u32::MAX, 0];
+    counting_sort(&mut source);
+    assert_eq!(source, vec![0, 0, 7, u32::MAX, u32::MAX]);
+
+    let mut source = vec![i64::MAX, i64::MIN, 0, -1, i64::MIN];
+    counting_sort(&mut source);
+    assert_eq!(source, vec![i64::MIN, i64::MIN, -1, 0, i64::MAX]);
+
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<f64> = (0..1000).map(|_| rng.gen_range(-1e6, 1e6)).collect();
+    source.push(f64::MAX);
+    source.push(f64::MIN);
+    let mut exp = source.clone();
+    exp.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    counting_sort(&mut source);
+    assert_eq!(source, exp);
+}
+
+#[test]
+fn test_counting_sort_wide_range_stable() {
+    let mut source: Vec<Record> = (0..100)
+        .map(|i| Record {
+            key: if i % 2 == 0 { i32::MIN } else { i32::MAX },
+            payload: i as usize,
+        })
+        .collect();
+    let mut exp = source.clone();
+    exp.sort_by_key(|r| r.key);
+    counting_sort(&mut source);
+    assert_eq!(source, exp);
+}
+
+#[test]
+#[should_panic]
+fn test_counting_sort_long_key() {
+    let mut source = vec!["a long string key"];
+    counting_sort(&mut source);
+}
@@ -0,0 +1,184 @@
+extern crate algorithm;
+extern crate rand;
+
+use std::collections::HashMap;
+
+use algorithm::graph::adjacency::Graph;
+use algorithm::search::depth::{depth_first_search, dfs_iter, EdgeKind, Visitor};
+use algorithm::sort::topological::topological_sort;
+use rand::Rng;
+
+/// Visitor recording everything it is told.
+struct Recorder {
+    discovered: HashMap<u32, usize>,
+    finished: HashMap<u32, usize>,
+    edges: Vec<(u32, u32, EdgeKind)>,
+}
+
+impl Recorder {
+    fn new() -> Self {
+        Recorder {
+            discovered: HashMap::new(),
+            finished: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    fn kind(&self, from: u32, to: u32) -> EdgeKind {
+        self.edges
+            .iter()
+            .find(|e| e.0 == from && e.1 == to)
+            .unwrap()
+            .2
+    }
+}
+
+impl Visitor<u32> for Recorder {
+    fn discover(&mut self, node: &u32, time: usize) {
+        self.discovered.insert(*node, time);
+    }
+
+    fn finish(&mut self, node: &u32, time: usize) {
+        self.finished.insert(*node, time);
+    }
+
+    fn edge(&mut self, from: &u32, to: &u32, kind: EdgeKind) {
+        self.edges.push((*from, *to, kind));
+    }
+}
+
+fn random_graph(n: u32, edges: usize) -> HashMap<u32, Vec<u32>> {
+    let mut rng = rand::thread_rng();
+    let mut graph = HashMap::new();
+    for node in 0..n {
+        graph.insert(node, Vec::new());
+    }
+    for _ in 0..edges {
+        let from = rng.gen_range(0, n);
+        let to = rng.gen_range(0, n);
+        if !graph[&from].contains(&to) {
+            graph.get_mut(&from).unwrap().push(to);
+        }
+    }
+    graph
+}
+
+fn recursive_preorder(graph: &HashMap<u32, Vec<u32>>, node: u32, order: &mut Vec<u32>) {
+    if order.contains(&node) {
+        return;
+    }
+    order.push(node);
+    for &next in &graph[&node] {
+        recursive_preorder(graph, next, order);
+    }
+}
+
+#[test]
+fn test_edge_kinds() {
+    // 1 -> 2 -> 3, with a forward edge 1 -> 3, a back edge 3 -> 1 and a
+    // cross edge 4 -> 2 from a second tree
+    let mut graph = HashMap::new();
+    graph.insert(1, vec![2, 3]);
+    graph.insert(2, vec![3]);
+    graph.insert(3, vec![1]);
+    graph.insert(4, vec![2]);
+
+    let mut recorder = Recorder::new();
+    depth_first_search(&graph, vec![1, 4], &mut recorder);
+    assert_eq!(recorder.kind(1, 2), EdgeKind::Tree);
+    assert_eq!(recorder.kind(2, 3), EdgeKind::Tree);
+    assert_eq!(recorder.kind(3, 1), EdgeKind::Back);
+    assert_eq!(recorder.kind(1, 3), EdgeKind::Forward);
+    assert_eq!(recorder.kind(4, 2), EdgeKind::Cross);
+    assert_eq!(recorder.edges.len(), 5);
+
+    let times: Vec<(usize, usize)> = (1..5)
+        .map(|n| (recorder.discovered[&n], recorder.finished[&n]))
+        .collect();
+    assert_eq!(times, vec![(1, 6), (2, 5), (3, 4), (7, 8)]);
+}
+
+#[test]
+fn test_random_parenthesis() {
+    for _ in 0..20 {
+        let graph = random_graph(60, 150);
+        let mut recorder = Recorder::new();
+        depth_first_search(&graph, 0..60, &mut recorder);
+
+        // every node discovered and finished once, every edge met once
+        assert_eq!(recorder.discovered.len(), 60);
+        assert_eq!(recorder.finished.len(), 60);
+        let edge_count: usize = graph.values().map(|targets| targets.len()).sum();
+        assert_eq!(recorder.edges.len(), edge_count);
+
+        let d = &recorder.discovered;
+        let f = &recorder.finished;
+        for &(from, to, kind) in &recorder.edges {
+            let nested = d[&from] < d[&to] && f[&to] < f[&from];
+            match kind {
+                EdgeKind::Tree | EdgeKind::Forward => assert!(nested),
+                EdgeKind::Back => assert!(d[&to] <= d[&from] && f[&from] <= f[&to]),
+                EdgeKind::Cross => assert!(f[&to] < d[&from]),
+            }
+        }
+
+        // a back edge exactly when there is a cycle
+        let back = recorder.edges.iter().any(|e| e.2 == EdgeKind::Back);
+        assert_eq!(back, topological_sort(&graph).is_err());
+    }
+}
+
+#[test]
+fn test_dfs_iter() {
+    for _ in 0..20 {
+        let graph = random_graph(50, 100);
+        let mut expected = Vec::new();
+        recursive_preorder(&graph, 0, &mut expected);
+        assert_eq!(dfs_iter(&graph, 0).collect::<Vec<_>>(), expected);
+    }
+
+    // a start without edges
+    let graph: HashMap<u32, Vec<u32>> = HashMap::new();
+    assert_eq!(dfs_iter(&graph, 7).collect::<Vec<_>>(), vec![7]);
+}
+
+#[test]
+fn test_deep() {
+    // a path far longer than a recursive search could follow
+    let n = 200_000;
+    let mut graph = HashMap::new();
+    for node in 0..n {
+        graph.insert(node, vec![node + 1]);
+    }
+
+    assert_eq!(dfs_iter(&graph, 0).count(), n as usize + 1);
+
+    struct Deepest(usize);
+    impl Visitor<u32> for Deepest {
+        fn discover(&mut self, _node: &u32, time: usize) {
+            self.0 = self.0.max(time);
+        }
+    }
+    let mut deepest = Deepest(0);
+    depth_first_search(&graph, vec![0], &mut deepest);
+    assert_eq!(deepest.0, n as usize + 1);
+}
+
+#[test]
+fn test_graph() {
+    let mut graph = Graph::directed();
+    graph.add_edge(1, 2, 1.5);
+    graph.add_edge(2, 3, 0.5);
+    graph.add_edge(3, 1, 2.0);
+
+    let mut order: Vec<u32> = dfs_iter(&graph, 2).collect();
+    assert_eq!(order, vec![2, 3, 1]);
+
+    let mut recorder = Recorder::new();
+    depth_first_search(&graph, vec![1], &mut recorder);
+    assert_eq!(recorder.kind(3, 1), EdgeKind::Back);
+
+    order = graph.nodes().cloned().collect();
+    order.sort();
+    assert_eq!(order, vec![1, 2, 3]);
+}
@@ -1,9 +1,15 @@
+// `search` is deprecated, but still covered
+#![allow(deprecated)]
+
 extern crate algorithm;
+extern crate rand;
 
 use std::collections::HashMap;
 use std::f32;
 
+use algorithm::graph::adjacency::Graph;
 use algorithm::search::dijkstra::*;
+use rand::Rng;
 
 
 #[test]
@@ -179,3 +185,191 @@ fn search_array_found_b() {
     println!("{:?}", path);
     assert_eq!(path, vec!["start", "a", "c", "fin"]);
 }
+
+#[test]
+fn search_unreachable() {
+    // <start> -> <a>, and <fin> on its own; <a> has no entry in the graph
+    let mut graph = HashMap::new();
+    let mut start_vertices = HashMap::new();
+    start_vertices.insert("a", 1_f32);
+    graph.insert("start", start_vertices);
+
+    let mut costs = HashMap::new();
+    costs.insert("a", 1_f32);
+    costs.insert("fin", f32::INFINITY);
+    let mut parents = HashMap::new();
+    parents.insert("a", Some("start"));
+    parents.insert("fin", None);
+
+    let path = search(&graph, &"start", &"fin", &mut costs, &mut parents);
+    assert!(path.is_empty());
+    let path = search(&graph, &"start", &"a", &mut costs, &mut parents);
+    assert_eq!(path, vec!["start", "a"]);
+}
+
+type Weighted = HashMap<&'static str, HashMap<&'static str, u32>>;
+
+fn weighted_graph(edges: &[(&'static str, &'static str, u32)]) -> Weighted {
+    let mut graph = HashMap::new();
+    for &(from, to, weight) in edges {
+        graph
+            .entry(from)
+            .or_insert_with(HashMap::new)
+            .insert(to, weight);
+    }
+    graph
+}
+
+#[test]
+fn test_dijkstra() {
+    // the graphs of the tests above, without pre-filled costs or parents
+    let graph = weighted_graph(&[
+        ("start", "a", 6),
+        ("start", "b", 2),
+        ("a", "fin", 1),
+        ("b", "a", 3),
+        ("b", "fin", 5),
+    ]);
+    let paths = dijkstra(&graph, "start");
+    assert_eq!(
+        paths.path(&"fin"),
+        Some((6, vec!["start", "b", "a", "fin"]))
+    );
+    assert_eq!(paths.cost(&"a"), Some(&5));
+    assert_eq!(paths.path(&"start"), Some((0, vec!["start"])));
+
+    let graph = weighted_graph(&[
+        ("start", "a", 5),
+        ("start", "b", 2),
+        ("a", "c", 4),
+        ("a", "d", 2),
+        ("b", "a", 8),
+        ("b", "d", 7),
+        ("c", "d", 6),
+        ("c", "fin", 3),
+        ("d", "fin", 1),
+    ]);
+    assert_eq!(
+        shortest_path(&graph, "start", &"fin"),
+        Some((8, vec!["start", "a", "d", "fin"]))
+    );
+
+    let graph = weighted_graph(&[
+        ("start", "a", 10),
+        ("a", "c", 20),
+        ("b", "a", 1),
+        ("c", "b", 1),
+        ("c", "fin", 30),
+    ]);
+    assert_eq!(
+        shortest_path(&graph, "start", &"fin"),
+        Some((60, vec!["start", "a", "c", "fin"]))
+    );
+}
+
+#[test]
+fn test_dijkstra_unreachable() {
+    let graph = weighted_graph(&[("a", "b", 1), ("c", "a", 1)]);
+    let paths = dijkstra(&graph, "a");
+    assert_eq!(paths.path(&"c"), None);
+    assert_eq!(paths.cost(&"missing"), None);
+    assert_eq!(paths.costs().len(), 2);
+
+    // a start which is not in the graph reaches only itself
+    let paths = dijkstra(&graph, "z");
+    assert_eq!(paths.path(&"z"), Some((0, vec!["z"])));
+    assert_eq!(shortest_path(&graph, "z", &"a"), None);
+}
+
+#[test]
+fn test_dijkstra_early_exit() {
+    // a line 0 -> 1 -> ... -> 99
+    let mut graph: HashMap<u32, HashMap<u32, u64>> = HashMap::new();
+    for node in 0..99 {
+        let mut next = HashMap::new();
+        next.insert(node + 1, 1);
+        graph.insert(node, next);
+    }
+
+    let paths = dijkstra_until(&graph, 0, |&node| node == 10);
+    assert_eq!(paths.cost(&10), Some(&10));
+    assert_eq!(paths.costs().len(), 11);
+    assert_eq!(paths.cost(&50), None);
+    assert_eq!(*paths.start(), 0);
+}
+
+#[test]
+fn test_dijkstra_random() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        let n = 40;
+        let mut edges = Vec::new();
+        let mut graph: HashMap<u32, HashMap<u32, u64>> = HashMap::new();
+        for _ in 0..150 {
+            let from = rng.gen_range(0, n);
+            let to = rng.gen_range(0, n);
+            let weight = rng.gen_range(0, 100);
+            graph
+                .entry(from)
+                .or_default()
+                .insert(to, weight);
+        }
+        for (&from, targets) in &graph {
+            for (&to, &weight) in targets {
+                edges.push((from, to, weight));
+            }
+        }
+
+        // Bellman-Ford
+        let mut expected: HashMap<u32, u64> = HashMap::new();
+        expected.insert(0, 0);
+        for _ in 0..n {
+            for &(from, to, weight) in &edges {
+                if let Some(&cost) = expected.get(&from) {
+                    if expected.get(&to).is_none_or(|&old| cost + weight < old) {
+                        expected.insert(to, cost + weight);
+                    }
+                }
+            }
+        }
+
+        let paths = dijkstra(&graph, 0);
+        assert_eq!(paths.costs(), &expected);
+        for node in 0..n {
+            match paths.path(&node) {
+                Some((cost, path)) => {
+                    // the path is made of edges and costs what it claims
+                    assert_eq!(path[0], 0);
+                    assert_eq!(*path.last().unwrap(), node);
+                    let total: u64 = path.windows(2).map(|w| graph[&w[0]][&w[1]]).sum();
+                    assert_eq!(total, cost);
+                }
+                None => assert!(!expected.contains_key(&node)),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_dijkstra_graph() {
+    let mut graph = Graph::undirected();
+    graph.add_edge('a', 'b', 7);
+    graph.add_edge('a', 'c', 9);
+    graph.add_edge('a', 'f', 14);
+    graph.add_edge('b', 'c', 10);
+    graph.add_edge('b', 'd', 15);
+    graph.add_edge('c', 'd', 11);
+    graph.add_edge('c', 'f', 2);
+    graph.add_edge('d', 'e', 6);
+    graph.add_edge('e', 'f', 9);
+
+    assert_eq!(
+        shortest_path(&graph, 'a', &'e'),
+        Some((20, vec!['a', 'c', 'f', 'e']))
+    );
+    // undirected, so the way back costs the same
+    assert_eq!(
+        shortest_path(&graph, 'e', &'a'),
+        Some((20, vec!['e', 'f', 'c', 'a']))
+    );
+}
@@ -0,0 +1,63 @@
+extern crate algorithm;
+use algorithm::tree::disjoint_set::{DisjointSet, DisjointSetMap};
+
+#[test]
+fn test_union_find() {
+    let mut set = DisjointSet::new(10);
+    assert_eq!(set.component_count(), 10);
+    for &(x, y) in &[(4, 3), (3, 8), (6, 5), (9, 4), (2, 1), (5, 0), (7, 2), (6, 1)] {
+        assert!(set.union(x, y));
+    }
+    assert!(!set.union(8, 9));
+    assert_eq!(set.component_count(), 2);
+    assert!(set.connected(0, 7));
+    assert!(set.connected(3, 9));
+    assert!(!set.connected(0, 9));
+    assert_eq!(set.find(8), set.find(4));
+}
+
+#[test]
+fn test_components() {
+    let mut set = DisjointSet::new(6);
+    set.union(5, 1);
+    set.union(2, 4);
+    set.union(4, 5);
+    assert_eq!(set.components(), vec![vec![0], vec![1, 2, 4, 5], vec![3]]);
+
+    let x = set.add();
+    assert_eq!(x, 6);
+    assert_eq!(set.len(), 7);
+    assert_eq!(set.component_count(), 4);
+}
+
+#[test]
+fn test_long_chain() {
+    let n = 100000;
+    let mut set = DisjointSet::new(n);
+    for i in 1..n {
+        set.union(i - 1, i);
+    }
+    assert_eq!(set.component_count(), 1);
+    assert!(set.connected(0, n - 1));
+}
+
+#[test]
+fn test_map() {
+    let mut set = DisjointSetMap::new();
+    assert!(set.make_set("jony"));
+    assert!(!set.make_set("jony"));
+    set.union("you", "alice");
+    set.union("you", "bob");
+    set.union("peggy", "thom");
+    assert_eq!(set.len(), 6);
+    assert_eq!(set.component_count(), 3);
+    assert!(set.connected(&"alice", &"bob"));
+    assert!(!set.connected(&"alice", &"thom"));
+    assert!(!set.connected(&"alice", &"nobody"));
+    assert_eq!(set.find(&"nobody"), None);
+    assert_eq!(set.find(&"bob"), Some(&"you"));
+    assert_eq!(
+        set.components(),
+        vec![vec!["jony"], vec!["you", "alice", "bob"], vec!["peggy", "thom"]]
+    );
+}
@@ -0,0 +1,61 @@
+extern crate algorithm;
+extern crate rand;
+
+mod common;
+
+use std::cell::Cell;
+
+use algorithm::search::binary::binary_search;
+use algorithm::search::exponential::exponential_search;
+use common::Probed;
+use rand::Rng;
+
+#[test]
+fn test_probes_near_front() {
+    let source: Vec<u32> = (0..1 << 20).collect();
+    let probes = Cell::new(0);
+    let probed = Probed::new(&source, &probes);
+    for target in 0..64 {
+        let exponential = probed.count(target, |s, t| exponential_search(s, t).is_some());
+        let binary = probed.count(target, binary_search);
+        // O(log i) instead of O(log n)
+        assert!(exponential < binary);
+    }
+
+    // and still O(log n) anywhere: the gallop, then a binary search of
+    // the last gap with up to two comparisons per probe
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let target = rng.gen_range(0, 1 << 20);
+        let exponential = probed.count(target, |s, t| exponential_search(s, t).is_some());
+        let binary = probed.count(target, binary_search);
+        assert!(exponential <= 4 * binary);
+    }
+}
+
+#[test]
+fn test_unbounded() {
+    use algorithm::search::exponential::exponential_search_by;
+
+    let mut probes = 0;
+    let found = exponential_search_by(|i| {
+        probes += 1;
+        Some((3 * i as u64).cmp(&3000))
+    });
+    assert_eq!(found, Some(1000));
+    assert!(probes <= 21);
+
+    // a source of 10 elements
+    let evens = |target: usize| {
+        move |i: usize| {
+            if i < 10 {
+                Some((2 * i).cmp(&target))
+            } else {
+                None
+            }
+        }
+    };
+    assert_eq!(exponential_search_by(evens(18)), Some(9));
+    assert_eq!(exponential_search_by(evens(20)), None);
+    assert_eq!(exponential_search_by(evens(0)), Some(0));
+}
@@ -0,0 +1,311 @@
+extern crate algorithm;
+extern crate rand;
+#[macro_use]
+extern crate serde_derive;
+
+use std::cmp::Reverse;
+use std::fs;
+use std::io::{self, Cursor};
+
+use algorithm::sort::external::{ExternalSort, TempDir, DEFAULT_FAN_IN};
+use rand::Rng;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    key: u32,
+    payload: String,
+}
+
+fn records(len: usize) -> Vec<Record> {
+    let mut rng = rand::thread_rng();
+    (0..len)
+        .map(|i| Record {
+            key: rng.gen_range(0, 100),
+            payload: format!("record {}", i),
+        })
+        .collect()
+}
+
+#[test]
+fn test_external_sort_in_memory() {
+    let mut sorter = ExternalSort::new(1 << 20).unwrap();
+    let mut result = Vec::new();
+    sorter
+        .sort(vec![3, 1, 2], |x: i64| {
+            result.push(x);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(result, vec![1, 2, 3]);
+    assert_eq!(sorter.run_count(), 0);
+}
+
+#[test]
+fn test_external_sort_spills_runs() {
+    let mut rng = rand::thread_rng();
+    let source: Vec<i64> = (0..20000).map(|_| rng.gen()).collect();
+    let mut exp = source.clone();
+    exp.sort();
+
+    let mut sorter = ExternalSort::new(4096).unwrap();
+    let dir = sorter.temp_dir().to_path_buf();
+    let mut result = Vec::new();
+    let mut runs = 0;
+    sorter
+        .sort(source, |x: i64| {
+            result.push(x);
+            runs = runs.max(fs::read_dir(&dir)?.count());
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(result, exp);
+    // every run file plus the manifest was on disk during the merge
+    assert!(runs > 10);
+
+    // the runs are gone after the sort, and the directory after the drop
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+    drop(sorter);
+    assert!(!dir.exists());
+}
+
+#[test]
+fn test_external_sort_fan_in() {
+    let source = records(5000);
+    let mut exp = source.clone();
+    exp.sort_by_key(|r| r.key);
+
+    let mut sorter = ExternalSort::new(2048).unwrap();
+    assert_eq!(sorter.fan_in(), DEFAULT_FAN_IN);
+    sorter.set_fan_in(3);
+    let dir = sorter.temp_dir().to_path_buf();
+    let mut result = Vec::new();
+    let mut files = 0;
+    sorter
+        .sort_by_key(source, |r: &Record| r.key, |r| {
+            result.push(r);
+            files = files.max(fs::read_dir(&dir)?.count());
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(result, exp);
+    // at most 3 runs and the manifest were left for the final merge
+    assert!(files > 1 && files <= 4);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_external_sort_fan_in_too_small() {
+    ExternalSort::new(1024).unwrap().set_fan_in(1);
+}
+
+#[test]
+fn test_external_sort_stable() {
+    let source = records(5000);
+    let mut exp = source.clone();
+    exp.sort_by_key(|r| r.key);
+
+    let mut sorter = ExternalSort::new(8192).unwrap();
+    let mut result = Vec::new();
+    sorter
+        .sort_by_key(source.clone(), |r: &Record| r.key, |r| {
+            result.push(r);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(result, exp);
+
+    exp.reverse();
+    let mut result = Vec::new();
+    sorter
+        .sort_by(source.into_iter().rev(), |a: &Record, b: &Record| b.key.cmp(&a.key), |r| {
+            result.push(r);
+            Ok(())
+        })
+        .unwrap();
+    exp.sort_by_key(|r| Reverse(r.key));
+    assert_eq!(result, exp);
+}
+
+#[test]
+fn test_external_sort_lines() {
+    let mut rng = rand::thread_rng();
+    let mut lines: Vec<String> = (0..3000)
+        .map(|_| {
+            let len = rng.gen_range(0, 10);
+            (0..len).map(|_| rng.gen_range(b'a', b'z') as char).collect()
+        })
+        .collect();
+    let input = lines.join("\n") + "\n";
+
+    let mut output = Vec::new();
+    let mut sorter = ExternalSort::new(1024).unwrap();
+    sorter.sort_lines(Cursor::new(input), &mut output).unwrap();
+
+    lines.sort();
+    assert_eq!(String::from_utf8(output).unwrap(), lines.join("\n") + "\n");
+}
+
+#[test]
+fn test_external_sort_fixed() {
+    let mut rng = rand::thread_rng();
+    let input: Vec<u8> = (0..4 * 1000).map(|_| rng.gen()).collect();
+
+    let mut output = Vec::new();
+    let mut sorter = ExternalSort::new(512).unwrap();
+    sorter.sort_fixed(Cursor::new(&input), 4, &mut output).unwrap();
+
+    let mut exp: Vec<&[u8]> = input.chunks(4).collect();
+    exp.sort();
+    assert_eq!(output, exp.concat());
+
+    // input not ending on a record boundary
+    let err = sorter.sort_fixed(Cursor::new(&input[..7]), 4, &mut Vec::new()).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    // kept for resuming after a failure
+    fs::remove_dir_all(sorter.temp_dir()).unwrap();
+}
+
+#[test]
+fn test_external_sort_resume() {
+    let source = records(4000);
+    let mut exp = source.clone();
+    exp.sort_by_key(|r| r.key);
+
+    // interrupted after the first half was spilled
+    let dir = {
+        let mut sorter = ExternalSort::new(4096).unwrap();
+        let result = sorter.sort_by_key(source[..2000].to_vec(), |r: &Record| r.key, |_| {
+            Err(io::Error::other("disk full"))
+        });
+        assert!(result.is_err());
+        assert!(sorter.run_count() > 0);
+        sorter.temp_dir().to_path_buf()
+    };
+    // kept for resuming
+    assert!(dir.exists());
+
+    let mut sorter = ExternalSort::resume(&dir, 4096).unwrap();
+    assert!(sorter.run_count() > 0);
+
+    let mut result = Vec::new();
+    sorter
+        .sort_by_key(source, |r: &Record| r.key, |r| {
+            result.push(r);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(result, exp);
+
+    // a directory the sorter did not create is emptied, not removed
+    drop(sorter);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+    fs::remove_dir(&dir).unwrap();
+}
+
+#[test]
+fn test_external_sort_resume_after_merge_pass() {
+    let source = records(4000);
+    let mut exp = source.clone();
+    exp.sort_by_key(|r| r.key);
+
+    // interrupted in the final merge, after the intermediate ones
+    let dir = {
+        let mut sorter = ExternalSort::new(1024).unwrap();
+        sorter.set_fan_in(4);
+        let result = sorter.sort_by_key(source.clone(), |r: &Record| r.key, |_| {
+            Err(io::Error::other("disk full"))
+        });
+        assert!(result.is_err());
+        assert!(sorter.run_count() > 1 && sorter.run_count() <= 4);
+        sorter.temp_dir().to_path_buf()
+    };
+
+    let mut sorter = ExternalSort::resume(&dir, 1024).unwrap();
+    // the manifest lists the intermediate runs only
+    assert!(sorter.run_count() > 1 && sorter.run_count() <= 4);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), sorter.run_count() + 1);
+
+    let mut result = Vec::new();
+    sorter
+        .sort_by_key(source, |r: &Record| r.key, |r| {
+            result.push(r);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(result, exp);
+    drop(sorter);
+    fs::remove_dir(&dir).unwrap();
+}
+
+#[test]
+fn test_external_sort_resume_keeps_other_files() {
+    let root = TempDir::new().unwrap();
+    fs::write(root.path().join("notes"), b"keep me").unwrap();
+    // not the directory of a sort
+    let error = ExternalSort::resume(root.path(), 4096).err().unwrap();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    assert!(root.path().join("notes").exists());
+
+    let source = records(4000);
+    let mut exp = source.clone();
+    exp.sort_by_key(|r| r.key);
+
+    let dir = root.path().join("sort");
+    {
+        let mut sorter = ExternalSort::resume(&dir, 4096).unwrap();
+        let result = sorter.sort_by_key(source[..2000].to_vec(), |r: &Record| r.key, |_| {
+            Err(io::Error::other("disk full"))
+        });
+        assert!(result.is_err());
+    }
+    fs::write(dir.join("notes"), b"keep me too").unwrap();
+
+    let mut sorter = ExternalSort::resume(&dir, 4096).unwrap();
+    let mut result = Vec::new();
+    sorter
+        .sort_by_key(source, |r: &Record| r.key, |r| {
+            result.push(r);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(result, exp);
+    drop(sorter);
+
+    let left: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(left, vec!["notes"]);
+    assert!(root.path().join("notes").exists());
+}
+
+#[test]
+fn test_temp_dir() {
+    let path = {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("file"), b"data").unwrap();
+        dir.path().to_path_buf()
+    };
+    assert!(!path.exists());
+
+    let path = {
+        let mut dir = TempDir::new().unwrap();
+        dir.keep_on_drop(true);
+        dir.path().to_path_buf()
+    };
+    assert!(path.exists());
+
+    // only tracked files are removed from a directory which existed
+    let mut dir = TempDir::open(&path).unwrap();
+    fs::write(dir.track("run"), b"data").unwrap();
+    fs::write(path.join("other"), b"data").unwrap();
+    drop(dir);
+    assert!(!path.join("run").exists());
+    assert!(path.join("other").exists());
+
+    let inner = path.join("inner");
+    drop(TempDir::open(&inner).unwrap());
+    assert!(!inner.exists());
+    fs::remove_dir_all(&path).unwrap();
+}
@@ -0,0 +1,72 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::tree::fenwick_tree::FenwickTree;
+use rand::Rng;
+
+#[test]
+fn test_prefix_sum() {
+    let tree = FenwickTree::from_slice(&[3, 2, -1, 6, 5, 4, -3, 3, 7, 2, 3]);
+    assert_eq!(tree.prefix_sum(0), 0);
+    assert_eq!(tree.prefix_sum(1), 3);
+    assert_eq!(tree.prefix_sum(5), 15);
+    assert_eq!(tree.prefix_sum(11), 31);
+}
+
+#[test]
+fn test_range_sum() {
+    let tree = FenwickTree::from_slice(&[1, 2, 3, 4, 5]);
+    assert_eq!(tree.range_sum(1, 4), 9);
+    assert_eq!(tree.range_sum(2, 2), 0);
+    assert_eq!(tree.range_sum(0, 5), 15);
+}
+
+#[test]
+fn test_add_and_set() {
+    let mut tree = FenwickTree::new(5);
+    tree.add(0, 1);
+    tree.add(3, 4);
+    tree.add(3, 2);
+    assert_eq!(tree.range_sum(0, 5), 7);
+    tree.set(3, 1);
+    assert_eq!(tree.range_sum(3, 4), 1);
+    assert_eq!(tree.prefix_sum(5), 2);
+}
+
+#[test]
+fn test_lower_bound() {
+    let tree = FenwickTree::from_slice(&[1, 0, 2, 1, 1, 3, 0, 4]);
+    assert_eq!(tree.lower_bound(0), 0);
+    assert_eq!(tree.lower_bound(1), 0);
+    assert_eq!(tree.lower_bound(2), 2);
+    assert_eq!(tree.lower_bound(3), 2);
+    assert_eq!(tree.lower_bound(5), 4);
+    assert_eq!(tree.lower_bound(12), 7);
+    assert_eq!(tree.lower_bound(13), 8);
+}
+
+#[test]
+fn test_empty() {
+    let tree: FenwickTree<i32> = FenwickTree::new(0);
+    assert!(tree.is_empty());
+    assert_eq!(tree.prefix_sum(0), 0);
+    assert_eq!(tree.lower_bound(1), 0);
+}
+
+#[test]
+fn test_random_against_naive() {
+    let mut rng = rand::thread_rng();
+    let mut values: Vec<i64> = (0..100).map(|_| rng.gen_range(-50, 50)).collect();
+    let mut tree = FenwickTree::from_slice(&values);
+    for _ in 0..200 {
+        let i = rng.gen_range(0, values.len());
+        let delta = rng.gen_range(-50, 50);
+        values[i] += delta;
+        tree.add(i, delta);
+
+        let start = rng.gen_range(0, values.len() + 1);
+        let end = rng.gen_range(start, values.len() + 1);
+        let exp: i64 = values[start..end].iter().sum();
+        assert_eq!(tree.range_sum(start, end), exp);
+    }
+}
@@ -0,0 +1,30 @@
+extern crate algorithm;
+extern crate rand;
+
+mod common;
+
+use std::cell::Cell;
+
+use algorithm::search::binary::binary_search;
+use algorithm::search::fibonacci::fibonacci_search;
+use common::Probed;
+use rand::Rng;
+
+#[test]
+fn test_probes() {
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<u32> = (0..100_000).map(|_| rng.gen()).collect();
+    source.sort();
+
+    let probes = Cell::new(0);
+    let probed = Probed::new(&source, &probes);
+    let mut fibonacci = 0;
+    let mut binary = 0;
+    for _ in 0..1000 {
+        let target = source[rng.gen_range(0, source.len())];
+        fibonacci += probed.count(target, |s, t| fibonacci_search(s, t).is_some());
+        binary += probed.count(target, binary_search);
+    }
+    // log_phi(n) probes of up to two comparisons, against log2(n) of one
+    assert!(fibonacci < 3 * binary);
+}
@@ -0,0 +1,195 @@
+extern crate algorithm;
+extern crate rand;
+
+use std::collections::HashMap;
+
+use algorithm::graph::adjacency::{Direction, Graph};
+use algorithm::graph::storage::{AdjacencyList, AdjacencyMatrix, Storage};
+use rand::Rng;
+
+fn sorted_neighbors<S: Storage<u32>>(graph: &Graph<u32, u32, S>, node: u32) -> Vec<(u32, u32)> {
+    let mut neighbors: Vec<(u32, u32)> = graph.neighbors(&node).map(|(&t, &w)| (t, w)).collect();
+    neighbors.sort();
+    neighbors
+}
+
+fn check_directed<S: Storage<u32>>() {
+    let mut graph: Graph<u32, u32, S> = Graph::new(Direction::Directed);
+    assert!(graph.is_directed());
+    assert!(graph.add_node(1));
+    assert!(!graph.add_node(1));
+    assert_eq!(graph.add_edge(1, 2, 10), None);
+    assert_eq!(graph.add_edge(1, 3, 20), None);
+    assert_eq!(graph.add_edge(3, 1, 30), None);
+    assert_eq!(graph.add_edge(1, 2, 15), Some(10));
+    assert_eq!(graph.node_count(), 3);
+    assert_eq!(graph.edge_count(), 3);
+
+    assert_eq!(graph.edge(&1, &2), Some(&15));
+    assert_eq!(graph.edge(&2, &1), None);
+    assert_eq!(graph.edge(&1, &9), None);
+    assert_eq!(sorted_neighbors(&graph, 1), vec![(2, 15), (3, 20)]);
+    assert_eq!(sorted_neighbors(&graph, 2), vec![]);
+    assert_eq!(sorted_neighbors(&graph, 9), vec![]);
+
+    assert_eq!(graph.remove_edge(&1, &3), Some(20));
+    assert_eq!(graph.remove_edge(&1, &3), None);
+    assert_eq!(graph.edge_count(), 2);
+
+    // removing a node removes the edges to it as well
+    assert!(graph.remove_node(&1));
+    assert!(!graph.remove_node(&1));
+    assert_eq!(graph.node_count(), 2);
+    assert_eq!(graph.edge_count(), 0);
+    assert_eq!(sorted_neighbors(&graph, 3), vec![]);
+    assert!(graph.contains_node(&2) && graph.contains_node(&3));
+}
+
+fn check_undirected<S: Storage<u32>>() {
+    let mut graph: Graph<u32, u32, S> = Graph::new(Direction::Undirected);
+    graph.add_edge(1, 2, 10);
+    graph.add_edge(2, 3, 20);
+    graph.add_edge(3, 3, 30);
+    assert_eq!(graph.edge_count(), 3);
+    assert_eq!(graph.edge(&2, &1), Some(&10));
+    assert_eq!(sorted_neighbors(&graph, 2), vec![(1, 10), (3, 20)]);
+    assert_eq!(sorted_neighbors(&graph, 3), vec![(2, 20), (3, 30)]);
+
+    let mut edges: Vec<(u32, u32)> = graph
+        .edges()
+        .iter()
+        .map(|&(&a, &b, _)| (a.min(b), a.max(b)))
+        .collect();
+    edges.sort();
+    assert_eq!(edges, vec![(1, 2), (2, 3), (3, 3)]);
+
+    assert_eq!(graph.remove_edge(&2, &1), Some(10));
+    assert_eq!(graph.edge(&1, &2), None);
+    assert_eq!(graph.edge_count(), 2);
+
+    assert!(graph.remove_node(&3));
+    assert_eq!(graph.edge_count(), 0);
+    assert_eq!(sorted_neighbors(&graph, 2), vec![]);
+}
+
+#[test]
+fn test_adjacency_list() {
+    check_directed::<AdjacencyList<u32>>();
+    check_undirected::<AdjacencyList<u32>>();
+}
+
+#[test]
+fn test_adjacency_matrix() {
+    check_directed::<AdjacencyMatrix<u32>>();
+    check_undirected::<AdjacencyMatrix<u32>>();
+}
+
+/// Random operations on both backings and a map of the expected edges.
+fn check_random(direction: Direction) {
+    let mut rng = rand::thread_rng();
+    let mut list: Graph<u32, u32> = Graph::new(direction);
+    let mut matrix: Graph<u32, u32, AdjacencyMatrix<u32>> = Graph::new(direction);
+    let mut expected: HashMap<(u32, u32), u32> = HashMap::new();
+
+    for _ in 0..2000 {
+        let a = rng.gen_range(0, 30);
+        let b = rng.gen_range(0, 30);
+        let (x, y) = if direction == Direction::Directed || a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        match rng.gen_range(0, 10) {
+            0 => {
+                let removed = list.remove_node(&a);
+                assert_eq!(matrix.remove_node(&a), removed);
+                expected.retain(|&(from, to), _| from != a && to != a);
+            }
+            1..=3 => {
+                let old = expected.remove(&(x, y));
+                assert_eq!(list.remove_edge(&a, &b), old);
+                assert_eq!(matrix.remove_edge(&a, &b), old);
+            }
+            _ => {
+                let weight = rng.gen();
+                let old = expected.insert((x, y), weight);
+                assert_eq!(list.add_edge(a, b, weight), old);
+                assert_eq!(matrix.add_edge(a, b, weight), old);
+            }
+        }
+
+        assert_eq!(list.edge_count(), expected.len());
+        assert_eq!(matrix.edge_count(), expected.len());
+        assert_eq!(list.node_count(), matrix.node_count());
+    }
+
+    for (&(from, to), weight) in &expected {
+        assert_eq!(list.edge(&from, &to), Some(weight));
+        assert_eq!(
+            matrix.edge(&to, &from).is_some(),
+            list.edge(&to, &from).is_some()
+        );
+    }
+    for &node in list.nodes() {
+        assert_eq!(
+            sorted_neighbors(&list, node),
+            sorted_neighbors(&matrix, node)
+        );
+    }
+}
+
+#[test]
+fn test_random() {
+    check_random(Direction::Directed);
+    check_random(Direction::Undirected);
+}
+
+#[test]
+fn test_from_adjacency_map() {
+    let mut map = HashMap::new();
+    map.insert("you", vec!["alice", "bob"]);
+    map.insert("bob", vec!["peggy"]);
+    map.insert("alice", vec!["peggy"]);
+
+    let graph: Graph<&str, ()> = Graph::from(&map);
+    assert!(graph.is_directed());
+    assert_eq!(graph.node_count(), 4);
+    assert_eq!(graph.edge_count(), 4);
+    assert!(graph.contains_edge(&"you", &"bob"));
+    assert!(!graph.contains_edge(&"bob", &"you"));
+
+    let mut back = graph.to_adjacency_map();
+    for targets in back.values_mut() {
+        targets.sort();
+    }
+    map.insert("peggy", vec![]);
+    assert_eq!(back, map);
+
+    // owned strings, on a matrix
+    let mut map = HashMap::new();
+    map.insert("a".to_string(), vec!["b".to_string()]);
+    let graph: Graph<String, (), AdjacencyMatrix<()>> = Graph::from(&map);
+    assert!(graph.contains_edge(&"a".to_string(), &"b".to_string()));
+}
+
+#[test]
+fn test_from_weighted_map() {
+    let mut map = HashMap::new();
+    let mut start = HashMap::new();
+    start.insert("a", 6_f32);
+    start.insert("b", 2_f32);
+    map.insert("start", start);
+    let mut b = HashMap::new();
+    b.insert("a", 3_f32);
+    map.insert("b", b);
+
+    let graph: Graph<&str, f32> = Graph::from(&map);
+    assert_eq!(graph.edge(&"start", &"a"), Some(&6.0));
+    assert_eq!(graph.edge(&"b", &"a"), Some(&3.0));
+    assert_eq!(graph.edge_count(), 3);
+
+    let back = graph.to_weighted_map();
+    assert_eq!(back["start"], map["start"]);
+    assert_eq!(back["b"], map["b"]);
+    assert!(back["a"].is_empty());
+}
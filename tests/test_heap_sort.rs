@@ -0,0 +1,42 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::sort::heap::heap_sort;
+use rand::Rng;
+
+#[test]
+fn test_heap_sort_array() {
+    let mut source = vec![3, 2, 5, 1, 4];
+    heap_sort(&mut source);
+    assert_eq!(source, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_heap_sort_empty() {
+    let mut source: Vec<i32> = Vec::new();
+    heap_sort(&mut source);
+    assert!(source.is_empty());
+}
+
+#[test]
+fn test_heap_sort_random() {
+    let mut rng = rand::thread_rng();
+    for len in [1, 2, 3, 10, 100, 1000] {
+        let mut source: Vec<i32> = (0..len).map(|_| rng.gen_range(-50, 50)).collect();
+        let mut exp = source.clone();
+        exp.sort();
+        heap_sort(&mut source);
+        assert_eq!(source, exp);
+    }
+}
+
+#[test]
+fn test_heap_sort_sorted_and_reversed() {
+    let mut source: Vec<i32> = (0..500).collect();
+    heap_sort(&mut source);
+    assert_eq!(source, (0..500).collect::<Vec<_>>());
+
+    let mut source: Vec<i32> = (0..500).rev().collect();
+    heap_sort(&mut source);
+    assert_eq!(source, (0..500).collect::<Vec<_>>());
+}
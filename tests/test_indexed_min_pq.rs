@@ -0,0 +1,67 @@
+extern crate algorithm;
+
+use algorithm::heap::indexed_min_pq::IndexedMinPQ;
+
+#[test]
+fn test_push_pop() {
+    let mut pq = IndexedMinPQ::new();
+    pq.push("a", 6_f32);
+    pq.push("b", 2_f32);
+    pq.push("fin", 7_f32);
+    assert_eq!(pq.len(), 3);
+    assert_eq!(pq.peek(), Some((&"b", &2_f32)));
+    assert_eq!(pq.pop(), Some(("b", 2_f32)));
+    assert_eq!(pq.pop(), Some(("a", 6_f32)));
+    assert_eq!(pq.pop(), Some(("fin", 7_f32)));
+    assert_eq!(pq.pop(), None);
+}
+
+#[test]
+fn test_push_existing_key() {
+    let mut pq = IndexedMinPQ::new();
+    pq.push('a', 3);
+    pq.push('b', 5);
+    assert_eq!(pq.push('b', 1), Some(5));
+    assert_eq!(pq.len(), 2);
+    assert_eq!(pq.pop(), Some(('b', 1)));
+}
+
+#[test]
+fn test_decrease_key() {
+    let mut pq = IndexedMinPQ::new();
+    pq.push('a', 3);
+    pq.push('b', 5);
+    assert!(!pq.decrease_key(&'b', 7));
+    assert!(!pq.decrease_key(&'z', 1));
+    assert!(pq.decrease_key(&'b', 2));
+    assert_eq!(pq.priority(&'b'), Some(&2));
+    assert_eq!(pq.pop(), Some(('b', 2)));
+}
+
+#[test]
+fn test_change_priority() {
+    let mut pq = IndexedMinPQ::new();
+    for (i, k) in "abcdefg".chars().enumerate() {
+        pq.push(k, i);
+    }
+    assert_eq!(pq.change_priority(&'a', 10), Some(0));
+    assert_eq!(pq.change_priority(&'g', 0), Some(6));
+    assert_eq!(pq.change_priority(&'z', 0), None);
+    let order: Vec<char> = (0..7).map(|_| pq.pop().unwrap().0).collect();
+    assert_eq!(order, vec!['g', 'b', 'c', 'd', 'e', 'f', 'a']);
+}
+
+#[test]
+fn test_remove() {
+    let mut pq = IndexedMinPQ::new();
+    for (i, k) in "abcdefg".chars().enumerate() {
+        pq.push(k, i);
+    }
+    assert_eq!(pq.remove(&'c'), Some(2));
+    assert_eq!(pq.remove(&'c'), None);
+    assert!(!pq.contains(&'c'));
+    assert_eq!(pq.remove(&'a'), Some(0));
+    let order: Vec<char> = (0..5).map(|_| pq.pop().unwrap().0).collect();
+    assert_eq!(order, vec!['b', 'd', 'e', 'f', 'g']);
+    assert!(pq.is_empty());
+}
@@ -0,0 +1,54 @@
+extern crate algorithm;
+extern crate rand;
+
+mod common;
+
+use algorithm::sort::insertion::{binary_insertion_sort, insertion_sort};
+use common::records;
+use rand::Rng;
+
+#[test]
+fn test_insertion_sort_array() {
+    let mut source = vec![3, 2, 5, 1, 4];
+    insertion_sort(&mut source);
+    assert_eq!(source, vec![1, 2, 3, 4, 5]);
+
+    let mut source = vec![3, 2, 5, 1, 4];
+    binary_insertion_sort(&mut source);
+    assert_eq!(source, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_insertion_sort_random() {
+    let mut rng = rand::thread_rng();
+    for len in [0, 1, 2, 3, 10, 100, 500] {
+        let source: Vec<i32> = (0..len).map(|_| rng.gen_range(-20, 20)).collect();
+        let mut exp = source.clone();
+        exp.sort();
+
+        let mut act = source.clone();
+        insertion_sort(&mut act);
+        assert_eq!(act, exp);
+
+        let mut act = source.clone();
+        binary_insertion_sort(&mut act);
+        assert_eq!(act, exp);
+    }
+}
+
+#[test]
+fn test_insertion_sort_stable() {
+    let source = records(500, 5);
+
+    let mut exp = source.clone();
+    exp.sort_by_key(|r| r.key);
+    let exp: Vec<usize> = exp.iter().map(|r| r.payload).collect();
+
+    let mut act = source.clone();
+    insertion_sort(&mut act);
+    assert_eq!(act.iter().map(|r| r.payload).collect::<Vec<_>>(), exp);
+
+    let mut act = source.clone();
+    binary_insertion_sort(&mut act);
+    assert_eq!(act.iter().map(|r| r.payload).collect::<Vec<_>>(), exp);
+}
@@ -0,0 +1,82 @@
+extern crate algorithm;
+extern crate rand;
+
+mod common;
+
+use std::cell::Cell;
+
+use algorithm::search::binary::binary_search;
+use algorithm::search::interpolation::{interpolation_search, interpolation_search_by_key};
+use common::Probed;
+use rand::Rng;
+
+#[test]
+fn test_probes_uniform() {
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<u32> = (0..100_000).map(|_| rng.gen()).collect();
+    source.sort();
+
+    let probes = Cell::new(0);
+    let probed = Probed::new(&source, &probes);
+    let mut interpolation = 0;
+    let mut binary = 0;
+    for _ in 0..1000 {
+        let target = source[rng.gen_range(0, source.len())];
+        interpolation += probed.count(target, |s, t| interpolation_search(s, t).is_some());
+        binary += probed.count(target, binary_search);
+    }
+    // O(log log n) against O(log n)
+    assert!(2 * interpolation < binary);
+}
+
+#[test]
+fn test_probes_skewed() {
+    // cubes, and a run of small values followed by a huge one, on which
+    // plain interpolation degrades to a linear scan
+    let cubes: Vec<u32> = (0..1600).map(|i| i * i * i).collect();
+    let mut outlier: Vec<u32> = (0..100_000).collect();
+    outlier.push(u32::MAX);
+
+    for source in &[cubes, outlier] {
+        let probes = Cell::new(0);
+        let probed = Probed::new(source, &probes);
+        for &target in source.iter().step_by(97) {
+            let interpolation = probed.count(target, |s, t| interpolation_search(s, t).is_some());
+            let binary = probed.count(target, binary_search);
+            assert!(interpolation <= 4 * binary);
+        }
+    }
+}
+
+#[test]
+fn test_duplicates() {
+    let source = [5u32; 100];
+    assert!(interpolation_search(&source, &5).is_some());
+    assert_eq!(interpolation_search(&source, &4), None);
+    assert_eq!(interpolation_search(&source, &6), None);
+}
+
+#[test]
+fn test_by_key() {
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<u64> = (0..10_000).map(|_| rng.gen()).collect();
+    source.push(u64::MAX);
+    source.push(0);
+    source.sort();
+    for target in &source {
+        let found = interpolation_search_by_key(&source, target, |&x| x as f64).unwrap();
+        assert_eq!(source[found], *target);
+    }
+
+    let source: Vec<i64> = (-500..500).map(|x| x * 1_000_000_007).collect();
+    let key = |&x: &i64| x as f64;
+    assert_eq!(interpolation_search_by_key(&source, &0, key), Some(500));
+    assert_eq!(interpolation_search_by_key(&source, &1, key), None);
+    assert_eq!(interpolation_search_by_key(&source, &i64::MIN, key), None);
+
+    let source: Vec<usize> = (0..100).collect();
+    assert_eq!(
+        interpolation_search_by_key(&source, &42, |&x| x as f64),
+        Some(42)
+    );
+}
@@ -0,0 +1,53 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::sort::intro::intro_sort;
+use rand::Rng;
+
+fn check(mut source: Vec<i64>) {
+    let mut exp = source.clone();
+    exp.sort();
+    intro_sort(&mut source);
+    assert_eq!(source, exp);
+}
+
+#[test]
+fn test_intro_sort_array() {
+    let mut source = vec![3, 2, 5, 1, 4];
+    intro_sort(&mut source);
+    assert_eq!(source, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_intro_sort_empty() {
+    let mut source: Vec<i64> = Vec::new();
+    intro_sort(&mut source);
+    assert!(source.is_empty());
+}
+
+#[test]
+fn test_intro_sort_random() {
+    let mut rng = rand::thread_rng();
+    for len in [10, 17, 100, 1000, 10000] {
+        check((0..len).map(|_| rng.gen_range(-1000, 1000)).collect());
+    }
+}
+
+#[test]
+fn test_intro_sort_sorted_runs() {
+    check((0..100000).collect());
+    check((0..100000).rev().collect());
+    check(vec![5; 10000]);
+}
+
+#[test]
+fn test_intro_sort_patterns() {
+    // organ pipe
+    check((0..5000).chain((0..5000).rev()).collect());
+    // sawtooth
+    check((0..10000).map(|i| i % 100).collect());
+    // sorted with a single element out of place
+    let mut source: Vec<i64> = (0..10000).collect();
+    source.swap(10, 9000);
+    check(source);
+}
@@ -0,0 +1,30 @@
+extern crate algorithm;
+extern crate rand;
+
+mod common;
+
+use std::cell::Cell;
+
+use algorithm::search::binary::binary_search;
+use algorithm::search::jump::jump_search;
+use common::Probed;
+use rand::Rng;
+
+#[test]
+fn test_probes() {
+    let source: Vec<u32> = (0..10_000).collect();
+    let probes = Cell::new(0);
+    let probed = Probed::new(&source, &probes);
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let target = rng.gen_range(0, 10_001);
+        let jump = probed.count(target, |s, t| jump_search(s, t).is_some());
+        // sqrt(n) blocks and sqrt(n) elements of one block
+        assert!(jump <= 2 * 100 + 2);
+    }
+
+    // the worst case of binary search is the best of jump search
+    let jump = probed.count(0, |s, t| jump_search(s, t).is_some());
+    let binary = probed.count(0, binary_search);
+    assert!(jump < binary);
+}
@@ -0,0 +1,56 @@
+extern crate algorithm;
+extern crate rand;
+
+mod common;
+
+use algorithm::sort::merge::{merge_sort, merge_sort_bottom_up};
+use common::{assert_stable, records};
+use rand::Rng;
+
+#[test]
+fn test_merge_sort_array() {
+    let mut source = vec![3, 2, 5, 1, 4];
+    merge_sort(&mut source);
+    assert_eq!(source, vec![1, 2, 3, 4, 5]);
+
+    let mut source = vec![3, 2, 5, 1, 4];
+    merge_sort_bottom_up(&mut source);
+    assert_eq!(source, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_merge_sort_empty() {
+    let mut source: Vec<i32> = Vec::new();
+    merge_sort(&mut source);
+    merge_sort_bottom_up(&mut source);
+    assert!(source.is_empty());
+}
+
+#[test]
+fn test_merge_sort_random() {
+    let mut rng = rand::thread_rng();
+    for len in [1, 2, 3, 10, 33, 1000] {
+        let source: Vec<i32> = (0..len).map(|_| rng.gen_range(-100, 100)).collect();
+        let mut exp = source.clone();
+        exp.sort();
+
+        let mut act = source.clone();
+        merge_sort(&mut act);
+        assert_eq!(act, exp);
+
+        let mut act = source.clone();
+        merge_sort_bottom_up(&mut act);
+        assert_eq!(act, exp);
+    }
+}
+
+#[test]
+fn test_merge_sort_stable() {
+    let mut source = records(2000, 10);
+    merge_sort(&mut source);
+    assert_stable(&source);
+
+    let mut source = records(2000, 10);
+    merge_sort_bottom_up(&mut source);
+    assert_stable(&source);
+}
@@ -0,0 +1,107 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::sort::network::{
+    apply_network, apply_network_branchless, batcher_network, sort16, sort4, sort8, SORT16, SORT4,
+    SORT8,
+};
+use algorithm::sort::quick::quick_sort;
+use rand::Rng;
+
+/// 0-1 principle: a comparator network sorts every input of `len`
+/// elements if and only if it sorts every sequence of 0s and 1s.
+fn is_sorting_network(network: &[(usize, usize)], len: usize) -> bool {
+    for bits in 0u32..1 << len {
+        let mut source: Vec<u32> = (0..len).map(|i| (bits >> i) & 1).collect();
+        apply_network(&mut source, network);
+        if source.windows(2).any(|w| w[0] > w[1]) {
+            return false;
+        }
+    }
+    true
+}
+
+#[test]
+fn test_optimal_networks() {
+    assert_eq!(SORT4.len(), 5);
+    assert_eq!(SORT8.len(), 19);
+    assert_eq!(SORT16.len(), 60);
+    assert!(is_sorting_network(&SORT4, 4));
+    assert!(is_sorting_network(&SORT8, 8));
+    assert!(is_sorting_network(&SORT16, 16));
+
+    // the verifier catches a missing comparator
+    assert!(!is_sorting_network(&SORT8[1..], 8));
+    assert!(!is_sorting_network(&SORT16[..59], 16));
+}
+
+#[test]
+fn test_batcher_network() {
+    for len in 0..17 {
+        assert!(is_sorting_network(&batcher_network(len), len));
+    }
+    assert_eq!(batcher_network(8).len(), 19);
+    assert_eq!(batcher_network(16).len(), 63);
+    assert!(batcher_network(1).is_empty());
+}
+
+#[test]
+fn test_sort_fixed_arrays() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let mut source: [i32; 4] = rng.gen();
+        let mut exp = source;
+        exp.sort();
+        sort4(&mut source);
+        assert_eq!(source, exp);
+
+        let mut source: [i32; 8] = rng.gen();
+        let mut exp = source;
+        exp.sort();
+        sort8(&mut source);
+        assert_eq!(source, exp);
+
+        let mut source: [f64; 16] = rng.gen();
+        let mut exp = source;
+        exp.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        sort16(&mut source);
+        assert_eq!(source, exp);
+    }
+}
+
+#[test]
+fn test_apply_network_branchless() {
+    let mut rng = rand::thread_rng();
+    for len in 0..17 {
+        let network = batcher_network(len);
+        for _ in 0..50 {
+            let source: Vec<i64> = (0..len).map(|_| rng.gen_range(-5, 5)).collect();
+            let mut exp = source.clone();
+            apply_network(&mut exp, &network);
+            let mut act = source.clone();
+            apply_network_branchless(&mut act, &network);
+            assert_eq!(act, exp);
+        }
+    }
+
+    let mut source: [f64; 16] = rng.gen();
+    let mut exp = source;
+    exp.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    apply_network_branchless(&mut source, &SORT16);
+    assert_eq!(source, exp);
+}
+
+#[test]
+fn test_quick_sort_small_partitions() {
+    // every length handled by the network base case
+    let mut rng = rand::thread_rng();
+    for len in 0..40 {
+        for _ in 0..20 {
+            let mut source: Vec<u8> = (0..len).map(|_| rng.gen_range(0, 4)).collect();
+            let mut exp = source.clone();
+            exp.sort();
+            quick_sort(&mut source);
+            assert_eq!(source, exp);
+        }
+    }
+}
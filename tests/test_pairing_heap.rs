@@ -0,0 +1,59 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::heap::pairing_heap::PairingHeap;
+use rand::Rng;
+
+#[test]
+fn test_push_pop() {
+    let mut heap = PairingHeap::new();
+    for v in [5, 3, 8, 1, 9, 2] {
+        heap.push(v);
+    }
+    assert_eq!(heap.len(), 6);
+    assert_eq!(heap.peek(), Some(&1));
+    let act: Vec<i32> = (0..6).map(|_| heap.pop().unwrap()).collect();
+    assert_eq!(act, vec![1, 2, 3, 5, 8, 9]);
+    assert_eq!(heap.pop(), None);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_meld() {
+    let mut a = PairingHeap::new();
+    let mut b = PairingHeap::new();
+    for v in [4, 8, 6] {
+        a.push(v);
+    }
+    for v in [7, 1, 5] {
+        b.push(v);
+    }
+    a.meld(b);
+    a.meld(PairingHeap::new());
+    assert_eq!(a.len(), 6);
+    let act: Vec<i32> = (0..6).map(|_| a.pop().unwrap()).collect();
+    assert_eq!(act, vec![1, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_random() {
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<u32> = (0..500).map(|_| rng.gen_range(0, 100)).collect();
+    let mut heap = PairingHeap::new();
+    for v in &source {
+        heap.push(*v);
+    }
+    source.sort();
+    let act: Vec<u32> = (0..source.len()).map(|_| heap.pop().unwrap()).collect();
+    assert_eq!(act, source);
+}
+
+#[test]
+fn test_drop_deep_heap() {
+    // descending pushes build a single chain
+    let mut heap = PairingHeap::new();
+    for v in (0..200000).rev() {
+        heap.push(v);
+    }
+    assert_eq!(heap.peek(), Some(&0));
+}
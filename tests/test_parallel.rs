@@ -0,0 +1,129 @@
+extern crate algorithm;
+extern crate rand;
+
+mod common;
+
+use algorithm::sort::merge::merge_sort;
+use algorithm::sort::parallel::{par_merge_sort, par_quick_sort, ParallelSort, DEFAULT_CUTOFF};
+use algorithm::sort::quick::quick_sort;
+use common::{records, Record};
+use rand::Rng;
+
+fn random(len: usize, range: i64) -> Vec<i64> {
+    let mut rng = rand::thread_rng();
+    (0..len).map(|_| rng.gen_range(-range, range)).collect()
+}
+
+#[test]
+fn test_parallel_sort_config() {
+    let sorter = ParallelSort::with_cutoff(3, 100);
+    assert_eq!(sorter.threads(), 3);
+    assert_eq!(sorter.cutoff(), 100);
+    assert_eq!(ParallelSort::new(2).cutoff(), DEFAULT_CUTOFF);
+}
+
+#[test]
+fn test_parallel_sort_matches_sequential() {
+    for &threads in &[1, 2, 4] {
+        for &cutoff in &[0, 1, 16, 1000] {
+            let sorter = ParallelSort::with_cutoff(threads, cutoff);
+            for &(len, range) in &[(0, 10), (1, 10), (100, 5), (20000, 1000000), (20000, 3)] {
+                let source = random(len, range);
+
+                let mut exp = source.clone();
+                quick_sort(&mut exp);
+                let mut act = source.clone();
+                sorter.quick_sort(&mut act);
+                assert_eq!(act, exp);
+
+                let mut exp = source.clone();
+                merge_sort(&mut exp);
+                let mut act = source.clone();
+                sorter.merge_sort(&mut act);
+                assert_eq!(act, exp);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_parallel_sort_sorted_input() {
+    let sorter = ParallelSort::with_cutoff(4, 64);
+    let exp: Vec<i64> = (0..50000).collect();
+
+    let mut act = exp.clone();
+    sorter.quick_sort(&mut act);
+    assert_eq!(act, exp);
+
+    let mut act: Vec<i64> = (0..50000).rev().collect();
+    sorter.merge_sort(&mut act);
+    assert_eq!(act, exp);
+}
+
+#[test]
+fn test_parallel_merge_sort_stable() {
+    let source = records(30000, 50);
+    let mut exp = source.clone();
+    merge_sort(&mut exp);
+
+    let sorter = ParallelSort::with_cutoff(4, 128);
+    let mut act = source.clone();
+    sorter.merge_sort(&mut act);
+    let payloads = |v: &[Record]| v.iter().map(|r| r.payload).collect::<Vec<_>>();
+    assert_eq!(payloads(&act), payloads(&exp));
+
+    let mut act = source.clone();
+    sorter.merge_sort_by_key(&mut act, |r| r.key);
+    assert_eq!(payloads(&act), payloads(&exp));
+}
+
+#[test]
+fn test_parallel_sort_by() {
+    let sorter = ParallelSort::with_cutoff(4, 32);
+    let source = random(10000, 1000);
+    let mut exp = source.clone();
+    exp.sort_by(|a, b| b.cmp(a));
+
+    let mut act = source.clone();
+    sorter.quick_sort_by(&mut act, |a, b| b.cmp(a));
+    assert_eq!(act, exp);
+
+    let mut act = source.clone();
+    sorter.merge_sort_by(&mut act, |a, b| b.cmp(a));
+    assert_eq!(act, exp);
+
+    let mut act = source.clone();
+    sorter.quick_sort_by_key(&mut act, |&x| -x);
+    assert_eq!(act, exp);
+}
+
+#[test]
+fn test_par_sort_global_pool() {
+    let source = random(100000, 1000000);
+    let mut exp = source.clone();
+    exp.sort();
+
+    let mut act = source.clone();
+    par_quick_sort(&mut act);
+    assert_eq!(act, exp);
+
+    let mut act = source.clone();
+    par_merge_sort(&mut act);
+    assert_eq!(act, exp);
+}
+
+#[test]
+fn test_parallel_quick_sort_in_place() {
+    // not `Clone`: the partitions are made by swapping in place
+    #[derive(Debug, PartialEq)]
+    struct Handle(i64);
+
+    let source = random(50000, 1000);
+    let mut exp = source.clone();
+    exp.sort();
+
+    let sorter = ParallelSort::with_cutoff(4, 64);
+    let mut act: Vec<Handle> = source.into_iter().map(Handle).collect();
+    sorter.quick_sort_by_key(&mut act, |h| h.0);
+    assert_eq!(act, exp.into_iter().map(Handle).collect::<Vec<_>>());
+}
@@ -1,9 +1,78 @@
 extern crate algorithm;
-use algorithm::sort::quick::quick_sort;
+extern crate rand;
+
+use algorithm::sort::quick::{quick_sort, quick_sort_with, PivotStrategy};
+use rand::Rng;
 
 #[test]
 fn test_quick_sort_array() {
     let mut source = vec![3, 2, 5, 1, 4];
     quick_sort(&mut source);
     assert_eq!(source, vec![1, 2, 3, 4, 5]);
-}
\ No newline at end of file
+}
+#[test]
+fn test_quick_sort_empty_and_single() {
+    let mut source: Vec<i32> = vec![];
+    quick_sort(&mut source);
+    assert!(source.is_empty());
+
+    let mut source = vec![1];
+    quick_sort(&mut source);
+    assert_eq!(source, vec![1]);
+}
+
+#[test]
+fn test_quick_sort_sub_slice() {
+    let mut source = vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0];
+    quick_sort(&mut source[2..8]);
+    assert_eq!(source, vec![9, 8, 2, 3, 4, 5, 6, 7, 1, 0]);
+}
+
+#[test]
+fn test_quick_sort_pivot_strategies() {
+    let strategies = vec![
+        PivotStrategy::MedianOfThree,
+        PivotStrategy::Ninther,
+        PivotStrategy::Random,
+    ];
+    let mut rng = rand::thread_rng();
+    for pivot in strategies {
+        let mut source: Vec<i64> = (0..1000).map(|_| rng.gen_range(-500, 500)).collect();
+        let mut exp = source.clone();
+        exp.sort();
+        quick_sort_with(&mut source, pivot);
+        assert_eq!(source, exp);
+    }
+}
+
+#[test]
+fn test_quick_sort_sorted_and_reversed() {
+    let mut source: Vec<u32> = (0..100000).collect();
+    quick_sort(&mut source);
+    assert_eq!(source, (0..100000).collect::<Vec<u32>>());
+
+    let mut source: Vec<u32> = (0..100000).rev().collect();
+    quick_sort(&mut source);
+    assert_eq!(source, (0..100000).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_quick_sort_duplicates() {
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<u8> = (0..100000).map(|_| rng.gen_range(0, 3)).collect();
+    let mut exp = source.clone();
+    exp.sort();
+    quick_sort(&mut source);
+    assert_eq!(source, exp);
+
+    let mut source = vec![7; 50000];
+    quick_sort(&mut source);
+    assert_eq!(source, vec![7; 50000]);
+}
+
+#[test]
+fn test_quick_sort_floats() {
+    let mut source = vec![2.5, -1.0, 3.25, 0.0, -7.5, 2.5];
+    quick_sort(&mut source);
+    assert_eq!(source, vec![-7.5, -1.0, 0.0, 2.5, 2.5, 3.25]);
+}
@@ -0,0 +1,118 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::sort::radix::{msd_radix_sort, radix_sort, RadixKey};
+use rand::Rng;
+
+/// Sorted by `key` only, `payload` records the original position.
+#[derive(Clone, Debug, PartialEq)]
+struct Record {
+    key: u16,
+    payload: usize,
+}
+
+impl RadixKey for Record {
+    fn key_len(&self) -> usize {
+        self.key.key_len()
+    }
+
+    fn key_byte(&self, index: usize) -> u8 {
+        self.key.key_byte(index)
+    }
+}
+
+fn check<T: RadixKey + Clone + PartialOrd + ::std::fmt::Debug>(source: Vec<T>) {
+    let mut exp = source.clone();
+    exp.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut act = source.clone();
+    radix_sort(&mut act);
+    assert_eq!(act, exp);
+
+    let mut act = source.clone();
+    msd_radix_sort(&mut act);
+    assert_eq!(act, exp);
+}
+
+#[test]
+fn test_radix_sort_unsigned() {
+    let mut rng = rand::thread_rng();
+    check::<u8>((0..1000).map(|_| rng.gen()).collect());
+    check::<u32>((0..1000).map(|_| rng.gen()).collect());
+    check::<u64>((0..1000).map(|_| rng.gen()).collect());
+    check::<usize>((0..1000).map(|_| rng.gen_range(0, 300)).collect());
+    check(vec![0u64, u64::MAX, 1, u64::MAX - 1]);
+    check::<u32>(Vec::new());
+}
+
+#[test]
+fn test_radix_sort_signed() {
+    let mut rng = rand::thread_rng();
+    check::<i8>((0..1000).map(|_| rng.gen()).collect());
+    check::<i32>((0..1000).map(|_| rng.gen()).collect());
+    check::<i64>((0..1000).map(|_| rng.gen_range(-1000, 1000)).collect());
+    check(vec![0i64, -1, 1, i64::MIN, i64::MAX]);
+}
+
+#[test]
+fn test_radix_sort_float() {
+    let mut rng = rand::thread_rng();
+    check::<f64>((0..1000).map(|_| rng.gen_range(-1e6, 1e6)).collect());
+    check::<f32>((0..1000).map(|_| rng.gen_range(-10.0, 10.0)).collect());
+    check(vec![
+        1.5f64,
+        -0.25,
+        f64::INFINITY,
+        0.0,
+        f64::NEG_INFINITY,
+        -1e300,
+        1e-300,
+    ]);
+
+    let mut source = vec![0.0f64, -0.0];
+    radix_sort(&mut source);
+    assert!(source[0].is_sign_negative() && source[1].is_sign_positive());
+}
+
+#[test]
+fn test_radix_sort_strings() {
+    check(vec!["banana", "", "apple", "app", "b", "cherry", "apple", "ap"]);
+
+    let mut rng = rand::thread_rng();
+    let words: Vec<String> = (0..2000)
+        .map(|_| {
+            let len = rng.gen_range(0, 8);
+            (0..len).map(|_| rng.gen_range(b'a', b'e') as char).collect()
+        })
+        .collect();
+    check(words);
+
+    let bytes: Vec<Vec<u8>> = (0..500)
+        .map(|_| {
+            let len = rng.gen_range(0, 5);
+            (0..len).map(|_| rng.gen()).collect()
+        })
+        .collect();
+    check(bytes);
+}
+
+#[test]
+fn test_radix_sort_stable() {
+    let mut rng = rand::thread_rng();
+    let source: Vec<Record> = (0..3000)
+        .map(|i| Record {
+            key: rng.gen_range(0, 600),
+            payload: i,
+        })
+        .collect();
+    let mut exp = source.clone();
+    exp.sort_by_key(|r| r.key);
+
+    let mut act = source.clone();
+    radix_sort(&mut act);
+    assert_eq!(act, exp);
+
+    let mut act = source.clone();
+    msd_radix_sort(&mut act);
+    assert_eq!(act, exp);
+}
@@ -0,0 +1,106 @@
+extern crate algorithm;
+use algorithm::tree::radix_tree::RadixTree;
+
+#[test]
+fn test_insert_get() {
+    let mut tree = RadixTree::new();
+    assert_eq!(tree.insert("tea", 1), None);
+    assert_eq!(tree.insert("ten", 2), None);
+    assert_eq!(tree.insert("tea", 3), Some(1));
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree.get("tea"), Some(&3));
+    assert_eq!(tree.get("te"), None);
+    assert_eq!(tree.get("team"), None);
+    assert!(tree.contains_key("ten"));
+}
+
+#[test]
+fn test_remove() {
+    let mut tree = RadixTree::new();
+    tree.insert("in", 1);
+    tree.insert("inn", 2);
+    tree.insert("int", 3);
+    assert_eq!(tree.remove("i"), None);
+    assert_eq!(tree.remove("inn"), Some(2));
+    assert_eq!(tree.remove("inn"), None);
+    assert_eq!(tree.len(), 2);
+    assert_eq!(tree.get("in"), Some(&1));
+    assert_eq!(tree.get("int"), Some(&3));
+
+    tree.remove("in");
+    tree.remove("int");
+    assert!(tree.is_empty());
+    assert_eq!(tree.iter().count(), 0);
+}
+
+#[test]
+fn test_prefix_iter() {
+    let mut tree = RadixTree::new();
+    for (i, w) in ["to", "tea", "ted", "ten", "i", "in", "inn", "A"].iter().enumerate() {
+        tree.insert(w, i);
+    }
+    let act: Vec<String> = tree.prefix_iter("te").map(|(k, _)| k).collect();
+    assert_eq!(act, vec!["tea", "ted", "ten"]);
+
+    let act: Vec<(String, &usize)> = tree.prefix_iter("in").collect();
+    assert_eq!(act, vec![("in".to_string(), &5), ("inn".to_string(), &6)]);
+
+    let act: Vec<String> = tree.iter().map(|(k, _)| k).collect();
+    assert_eq!(act, vec!["A", "i", "in", "inn", "tea", "ted", "ten", "to"]);
+
+    assert_eq!(tree.prefix_iter("x").count(), 0);
+}
+
+#[test]
+fn test_longest_prefix_match() {
+    let mut tree = RadixTree::new();
+    tree.insert("192.168", "lan");
+    tree.insert("192.168.1", "office");
+    assert_eq!(tree.longest_prefix_match("192.168.1.20"), Some(("192.168.1", &"office")));
+    assert_eq!(tree.longest_prefix_match("192.168.2.1"), Some(("192.168", &"lan")));
+    assert_eq!(tree.longest_prefix_match("10.0.0.1"), None);
+}
+
+#[test]
+fn test_unicode_keys() {
+    let mut tree = RadixTree::new();
+    tree.insert("中文", 1);
+    tree.insert("中文测试", 2);
+    tree.insert("中国", 3);
+    tree.insert("héllo", 4);
+
+    let act: Vec<String> = tree.prefix_iter("中").map(|(k, _)| k).collect();
+    assert_eq!(act, vec!["中国", "中文", "中文测试"]);
+    assert_eq!(tree.longest_prefix_match("中文测"), Some(("中文", &1)));
+    assert_eq!(tree.get("hé"), None);
+    assert_eq!(tree.prefix_iter("hé").count(), 1);
+}
+
+#[test]
+fn test_split_and_merge_edges() {
+    let mut tree = RadixTree::new();
+    tree.insert("romane", 1);
+    tree.insert("romanus", 2);
+    tree.insert("romulus", 3);
+    tree.insert("rubens", 4);
+    tree.insert("ruber", 5);
+    tree.insert("rubicon", 6);
+    tree.insert("rubicundus", 7);
+    assert_eq!(tree.len(), 7);
+    assert_eq!(tree.get("rom"), None);
+    assert_eq!(tree.get("rubicon"), Some(&6));
+
+    // "rom" is only the label of an inner edge
+    let act: Vec<String> = tree.prefix_iter("rom").map(|(k, _)| k).collect();
+    assert_eq!(act, vec!["romane", "romanus", "romulus"]);
+    // prefix ends in the middle of an edge
+    let act: Vec<String> = tree.prefix_iter("rubic").map(|(k, _)| k).collect();
+    assert_eq!(act, vec!["rubicon", "rubicundus"]);
+
+    assert_eq!(tree.remove("romanus"), Some(2));
+    assert_eq!(tree.remove("romulus"), Some(3));
+    assert_eq!(tree.get("romane"), Some(&1));
+    assert_eq!(tree.remove("rubicon"), Some(6));
+    let act: Vec<String> = tree.iter().map(|(k, _)| k).collect();
+    assert_eq!(act, vec!["romane", "rubens", "ruber", "rubicundus"]);
+}
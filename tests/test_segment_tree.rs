@@ -0,0 +1,90 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::tree::segment_tree::{Max, Min, SegmentTree, Sum};
+use rand::Rng;
+
+#[test]
+fn test_query_sum() {
+    let tree: SegmentTree<i32, Sum> = SegmentTree::new(&[1, 3, 5, 7, 9, 11]);
+    assert_eq!(tree.query(0, 6), Some(36));
+    assert_eq!(tree.query(1, 4), Some(15));
+    assert_eq!(tree.query(2, 2), None);
+}
+
+#[test]
+fn test_range_update_sum() {
+    let mut tree: SegmentTree<i32, Sum> = SegmentTree::new(&[1, 3, 5, 7, 9, 11]);
+    tree.update(1, 5, 2);
+    assert_eq!(tree.query(0, 6), Some(44));
+    assert_eq!(tree.query(4, 6), Some(22));
+    tree.update(0, 3, -1);
+    assert_eq!(tree.query(0, 2), Some(4));
+}
+
+#[test]
+fn test_range_update_sum_near_max() {
+    let mut single: SegmentTree<i32, Sum> = SegmentTree::new(&[0]);
+    single.update(0, 1, 1 << 30);
+    assert_eq!(single.query(0, 1), Some(1 << 30));
+
+    let delta = i32::MAX / 3;
+    let mut tree: SegmentTree<i32, Sum> = SegmentTree::new(&[0, 0, 0]);
+    tree.update(0, 3, delta);
+    assert_eq!(tree.query(0, 3), Some(3 * delta));
+}
+
+#[test]
+fn test_min_max() {
+    let mut min: SegmentTree<i32, Min> = SegmentTree::new(&[5, 3, 8, 6, 1, 9]);
+    let mut max: SegmentTree<i32, Max> = SegmentTree::new(&[5, 3, 8, 6, 1, 9]);
+    assert_eq!(min.query(0, 4), Some(3));
+    assert_eq!(max.query(0, 4), Some(8));
+
+    min.update(3, 6, 10);
+    max.update(3, 6, 10);
+    assert_eq!(min.query(2, 6), Some(8));
+    assert_eq!(max.query(0, 3), Some(8));
+    assert_eq!(max.query(0, 6), Some(19));
+}
+
+#[test]
+fn test_set() {
+    let mut tree: SegmentTree<i32, Min> = SegmentTree::new(&[4, 2, 7]);
+    tree.update(0, 3, 1);
+    tree.set(1, 9);
+    assert_eq!(tree.query(0, 3), Some(5));
+    assert_eq!(tree.query(1, 2), Some(9));
+}
+
+#[test]
+fn test_empty() {
+    let tree: SegmentTree<i32, Sum> = SegmentTree::new(&[]);
+    assert!(tree.is_empty());
+    assert_eq!(tree.query(0, 0), None);
+}
+
+#[test]
+fn test_random_against_naive() {
+    let mut rng = rand::thread_rng();
+    let mut values: Vec<i64> = (0..64).map(|_| rng.gen_range(-100, 100)).collect();
+    let mut sum: SegmentTree<i64, Sum> = SegmentTree::new(&values);
+    let mut min: SegmentTree<i64, Min> = SegmentTree::new(&values);
+    for _ in 0..300 {
+        let start = rng.gen_range(0, values.len());
+        let end = rng.gen_range(start + 1, values.len() + 1);
+        if rng.gen() {
+            let delta = rng.gen_range(-20, 20);
+            for v in &mut values[start..end] {
+                *v += delta;
+            }
+            sum.update(start, end, delta);
+            min.update(start, end, delta);
+        } else {
+            let exp_sum: i64 = values[start..end].iter().sum();
+            let exp_min = *values[start..end].iter().min().unwrap();
+            assert_eq!(sum.query(start, end), Some(exp_sum));
+            assert_eq!(min.query(start, end), Some(exp_min));
+        }
+    }
+}
@@ -1,5 +1,12 @@
 extern crate algorithm;
-use algorithm::sort::select::selection_sort;
+extern crate rand;
+
+use algorithm::sort::select::{
+    partial_sort, partial_sort_by, partial_sort_by_key, select_nth_median_of_medians,
+    select_nth_unstable, select_nth_unstable_by, select_nth_unstable_by_key, selection_sort,
+    selection_sort_by_key, top_k,
+};
+use rand::Rng;
 
 #[test]
 fn test_sort_array() {
@@ -7,3 +14,119 @@ fn test_sort_array() {
     selection_sort(&mut source);
     assert_eq!(source, vec![1, 2, 3, 4, 5]);
 }
+
+#[test]
+fn test_sort_slice() {
+    let mut source = vec![5, 4, 3, 2, 1, 0];
+    selection_sort(&mut source[1..5]);
+    assert_eq!(source, vec![5, 1, 2, 3, 4, 0]);
+}
+
+#[test]
+fn test_sort_stable() {
+    let mut rng = rand::thread_rng();
+    // (key, original position)
+    let mut source: Vec<(u32, usize)> = (0..500).map(|i| (rng.gen_range(0, 10), i)).collect();
+    let mut exp = source.clone();
+    exp.sort_by_key(|&(key, _)| key);
+    selection_sort_by_key(&mut source, |&(key, _)| key);
+    assert_eq!(source, exp);
+}
+
+fn check_selected(source: &[i32], k: usize) {
+    let mut sorted = source.to_vec();
+    sorted.sort();
+    assert_eq!(source[k], sorted[k]);
+    assert!(source[..k].iter().all(|&x| x <= source[k]));
+    assert!(source[k + 1..].iter().all(|&x| x >= source[k]));
+}
+
+#[test]
+fn test_select_nth_unstable() {
+    let mut rng = rand::thread_rng();
+    for len in [1, 2, 10, 17, 100, 5000] {
+        for _ in 0..10 {
+            let mut source: Vec<i32> = (0..len).map(|_| rng.gen_range(-50, 50)).collect();
+            let k = rng.gen_range(0, len);
+            let mut exp = source.clone();
+            exp.sort();
+            assert_eq!(*select_nth_unstable(&mut source, k), exp[k]);
+            check_selected(&source, k);
+        }
+    }
+}
+
+#[test]
+fn test_select_nth_median_of_medians() {
+    let mut rng = rand::thread_rng();
+    for len in [1, 2, 5, 6, 17, 100, 5000] {
+        for _ in 0..10 {
+            let mut source: Vec<i32> = (0..len).map(|_| rng.gen_range(-50, 50)).collect();
+            let k = rng.gen_range(0, len);
+            let mut exp = source.clone();
+            exp.sort();
+            assert_eq!(*select_nth_median_of_medians(&mut source, k), exp[k]);
+            check_selected(&source, k);
+        }
+    }
+
+    // sorted and reversed input, where a bad pivot rule degrades
+    let mut source: Vec<i32> = (0..10000).collect();
+    assert_eq!(*select_nth_median_of_medians(&mut source, 1234), 1234);
+    let mut source: Vec<i32> = (0..10000).rev().collect();
+    assert_eq!(*select_nth_median_of_medians(&mut source, 9999), 9999);
+}
+
+#[test]
+fn test_select_nth_unstable_by() {
+    let mut source = vec!["ccc", "a", "bb", "dddd"];
+    assert_eq!(*select_nth_unstable_by(&mut source, 0, |a, b| b.cmp(a)), "dddd");
+    assert_eq!(*select_nth_unstable_by_key(&mut source, 1, |s| s.len()), "bb");
+}
+
+#[test]
+#[should_panic]
+fn test_select_nth_unstable_out_of_range() {
+    let mut source = vec![1, 2, 3];
+    select_nth_unstable(&mut source, 3);
+}
+
+#[test]
+fn test_top_k() {
+    let mut rng = rand::thread_rng();
+    let source: Vec<i32> = (0..10000).map(|_| rng.gen()).collect();
+    let mut exp = source.clone();
+    exp.sort_by(|a, b| b.cmp(a));
+    for &k in &[0, 1, 10, 9999, 10000, 20000] {
+        let n = if k < exp.len() { k } else { exp.len() };
+        assert_eq!(top_k(source.iter().cloned(), k), exp[..n].to_vec());
+    }
+
+    // streaming input
+    assert_eq!(top_k((0..1_000_000).map(|x| x % 1000), 3), vec![999, 999, 999]);
+}
+
+#[test]
+fn test_partial_sort() {
+    let mut rng = rand::thread_rng();
+    for len in [0, 1, 10, 1000] {
+        let source: Vec<i32> = (0..len).map(|_| rng.gen_range(-100, 100)).collect();
+        let mut exp = source.clone();
+        exp.sort();
+        for &k in &[0, 1, 5, 500, 1000, 2000] {
+            let mut act = source.clone();
+            partial_sort(&mut act, k);
+            let n = if k < len { k } else { len };
+            assert_eq!(act[..n].to_vec(), exp[..n].to_vec());
+            let mut rest = act[n..].to_vec();
+            rest.sort();
+            assert_eq!(rest, exp[n..].to_vec());
+        }
+    }
+
+    let mut source = vec![3, 1, 2, 5, 4];
+    partial_sort_by(&mut source, 2, |a, b| b.cmp(a));
+    assert_eq!(source[..2], [5, 4]);
+    partial_sort_by_key(&mut source, 2, |&x| x);
+    assert_eq!(source[..2], [1, 2]);
+}
@@ -0,0 +1,47 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::sort::shell::{shell_sort, shell_sort_with, GapSequence};
+use rand::Rng;
+
+const SEQUENCES: [GapSequence; 4] = [
+    GapSequence::Shell,
+    GapSequence::Knuth,
+    GapSequence::Ciura,
+    GapSequence::Sedgewick,
+];
+
+#[test]
+fn test_shell_sort_array() {
+    let mut source = vec![3, 2, 5, 1, 4];
+    shell_sort(&mut source);
+    assert_eq!(source, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_gaps() {
+    assert_eq!(GapSequence::Shell.gaps(20), vec![10, 5, 2, 1]);
+    assert_eq!(GapSequence::Knuth.gaps(100), vec![40, 13, 4, 1]);
+    assert_eq!(GapSequence::Ciura.gaps(100), vec![57, 23, 10, 4, 1]);
+    assert_eq!(GapSequence::Ciura.gaps(5000), vec![3937, 1750, 701, 301, 132, 57, 23, 10, 4, 1]);
+    assert_eq!(GapSequence::Sedgewick.gaps(300), vec![281, 77, 23, 8, 1]);
+    for seq in SEQUENCES.iter() {
+        assert!(seq.gaps(0).is_empty());
+        assert!(seq.gaps(1).is_empty());
+        assert_eq!(seq.gaps(2), vec![1]);
+    }
+}
+
+#[test]
+fn test_shell_sort_random() {
+    let mut rng = rand::thread_rng();
+    for &seq in SEQUENCES.iter() {
+        for len in [0, 1, 2, 3, 10, 100, 5000] {
+            let mut source: Vec<i32> = (0..len).map(|_| rng.gen_range(-100, 100)).collect();
+            let mut exp = source.clone();
+            exp.sort();
+            shell_sort_with(&mut source, seq);
+            assert_eq!(source, exp);
+        }
+    }
+}
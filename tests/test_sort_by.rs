@@ -0,0 +1,144 @@
+extern crate algorithm;
+extern crate rand;
+
+use std::cmp::Ordering;
+
+use algorithm::sort::bucket::bucket_sort_by_key;
+use algorithm::sort::cached::sort_by_cached_key;
+use algorithm::sort::counting::counting_sort_by_key;
+use algorithm::sort::heap::{heap_sort_by, heap_sort_by_key};
+use algorithm::sort::insertion::{
+    binary_insertion_sort_by, binary_insertion_sort_by_key, insertion_sort_by,
+    insertion_sort_by_key,
+};
+use algorithm::sort::intro::{intro_sort_by, intro_sort_by_key};
+use algorithm::sort::merge::{
+    merge_sort_bottom_up_by, merge_sort_bottom_up_by_key, merge_sort_by, merge_sort_by_key,
+};
+use algorithm::sort::quick::{quick_sort_by, quick_sort_by_key};
+use algorithm::sort::radix::{msd_radix_sort_by_key, radix_sort_by_key};
+use algorithm::sort::select::{selection_sort_by, selection_sort_by_key};
+use algorithm::sort::shell::{shell_sort_by, shell_sort_by_key};
+use algorithm::sort::tim::{tim_sort_by, tim_sort_by_key};
+use rand::Rng;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+type SortBy = fn(&mut [Person], fn(&Person, &Person) -> Ordering);
+type SortByKey = fn(&mut [Person], fn(&Person) -> u32);
+
+fn people(len: usize) -> Vec<Person> {
+    let mut rng = rand::thread_rng();
+    (0..len)
+        .map(|i| Person {
+            name: format!("person {}", i),
+            age: rng.gen_range(0, 20),
+        })
+        .collect()
+}
+
+fn by_age_desc(a: &Person, b: &Person) -> Ordering {
+    b.age.cmp(&a.age)
+}
+
+fn age(p: &Person) -> u32 {
+    p.age
+}
+
+fn check_unstable(sort_by: SortBy, sort_by_key: SortByKey) {
+    for len in [0, 1, 5, 100, 1000] {
+        let source = people(len);
+
+        let mut act = source.clone();
+        sort_by(&mut act, by_age_desc);
+        let mut exp: Vec<u32> = source.iter().map(age).collect();
+        exp.sort_by(|a, b| b.cmp(a));
+        assert_eq!(act.iter().map(age).collect::<Vec<_>>(), exp);
+
+        let mut act = source.clone();
+        sort_by_key(&mut act, age);
+        exp.reverse();
+        assert_eq!(act.iter().map(age).collect::<Vec<_>>(), exp);
+    }
+}
+
+fn check_stable(sort_by: SortBy, sort_by_key: SortByKey) {
+    for len in [0, 1, 5, 100, 1000] {
+        let source = people(len);
+
+        let mut act = source.clone();
+        sort_by(&mut act, by_age_desc);
+        let mut exp = source.clone();
+        exp.sort_by(by_age_desc);
+        assert_eq!(act, exp);
+
+        let mut act = source.clone();
+        sort_by_key(&mut act, age);
+        let mut exp = source.clone();
+        exp.sort_by_key(age);
+        assert_eq!(act, exp);
+    }
+}
+
+#[test]
+fn test_unstable_sorts() {
+    check_unstable(quick_sort_by, quick_sort_by_key);
+    check_unstable(intro_sort_by, intro_sort_by_key);
+    check_unstable(heap_sort_by, heap_sort_by_key);
+    check_unstable(shell_sort_by, shell_sort_by_key);
+    check_unstable(selection_sort_by, selection_sort_by_key);
+}
+
+#[test]
+fn test_stable_sorts() {
+    check_stable(merge_sort_by, merge_sort_by_key);
+    check_stable(merge_sort_bottom_up_by, merge_sort_bottom_up_by_key);
+    check_stable(tim_sort_by, tim_sort_by_key);
+    check_stable(insertion_sort_by, insertion_sort_by_key);
+    check_stable(binary_insertion_sort_by, binary_insertion_sort_by_key);
+}
+
+#[test]
+fn test_key_sorts() {
+    let source = people(1000);
+    let mut exp = source.clone();
+    exp.sort_by_key(age);
+
+    let mut act = source.clone();
+    radix_sort_by_key(&mut act, age);
+    assert_eq!(act, exp);
+
+    let mut act = source.clone();
+    msd_radix_sort_by_key(&mut act, |p| p.name.clone());
+    let mut by_name = source.clone();
+    by_name.sort_by(|a, b| a.name.cmp(&b.name));
+    assert_eq!(act, by_name);
+
+    let mut act = source.clone();
+    counting_sort_by_key(&mut act, age);
+    assert_eq!(act, exp);
+
+    let mut act = source.clone();
+    bucket_sort_by_key(&mut act, age);
+    assert_eq!(act, exp);
+}
+
+#[test]
+fn test_sort_by_cached_key() {
+    let source = people(1000);
+    let mut exp = source.clone();
+    exp.sort_by_key(age);
+
+    let mut calls = 0;
+    let mut act = source.clone();
+    sort_by_cached_key(&mut act, |p| {
+        calls += 1;
+        p.age
+    });
+    assert_eq!(act, exp);
+    assert_eq!(calls, 1000);
+}
@@ -0,0 +1,123 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::sort::quick::quick_sort;
+use algorithm::sort::sorted::{
+    count_inversions, dedup_sorted, is_sorted, is_sorted_by, is_sorted_by_key, k_way_merge,
+    merge, merge_by,
+};
+use rand::Rng;
+
+#[test]
+fn test_is_sorted() {
+    let empty: [i32; 0] = [];
+    assert!(is_sorted(&empty));
+    assert!(is_sorted(&[1]));
+    assert!(is_sorted(&[1, 1, 2]));
+    assert!(!is_sorted(&[2, 1]));
+
+    assert!(is_sorted_by(&[3, 2, 2, 1], |a, b| b.cmp(a)));
+    assert!(!is_sorted_by(&[1, 2], |a, b| b.cmp(a)));
+    assert!(is_sorted_by_key(&["a", "bb", "cc", "ddd"], |s| s.len()));
+    assert!(!is_sorted_by_key(&["aa", "b"], |s| s.len()));
+
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<i32> = (0..1000).map(|_| rng.gen()).collect();
+    quick_sort(&mut source);
+    assert!(is_sorted(&source));
+}
+
+#[test]
+fn test_merge() {
+    assert_eq!(merge(&[1, 3, 5], &[2, 4]), vec![1, 2, 3, 4, 5]);
+    assert_eq!(merge(&[], &[1, 2]), vec![1, 2]);
+    assert_eq!(merge::<i32>(&[], &[]), Vec::<i32>::new());
+
+    // stable: ties taken from `a` first
+    let a = [(1, 'a'), (2, 'a')];
+    let b = [(1, 'b'), (2, 'b')];
+    assert_eq!(
+        merge_by(&a, &b, |x, y| x.0.cmp(&y.0)),
+        vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]
+    );
+}
+
+#[test]
+fn test_k_way_merge() {
+    let mut rng = rand::thread_rng();
+    let mut runs = Vec::new();
+    let mut exp = Vec::new();
+    for _ in 0..10 {
+        let len = rng.gen_range(0, 100);
+        let mut run: Vec<i32> = (0..len).map(|_| rng.gen_range(0, 50)).collect();
+        run.sort();
+        exp.extend(run.iter().cloned());
+        runs.push(run.into_iter());
+    }
+    exp.sort();
+    assert_eq!(k_way_merge(runs).collect::<Vec<_>>(), exp);
+
+    let none: Vec<::std::vec::IntoIter<i32>> = Vec::new();
+    assert_eq!(k_way_merge(none).next(), None);
+}
+
+#[test]
+fn test_k_way_merge_stable() {
+    #[derive(Debug, PartialEq)]
+    struct Tagged(i32, usize);
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Tagged) -> Option<::std::cmp::Ordering> {
+            self.0.partial_cmp(&other.0)
+        }
+    }
+
+    let runs = vec![
+        vec![Tagged(1, 0), Tagged(2, 0)].into_iter(),
+        vec![Tagged(1, 1), Tagged(2, 1)].into_iter(),
+        vec![Tagged(1, 2)].into_iter(),
+    ];
+    let tags: Vec<usize> = k_way_merge(runs).map(|t| t.1).collect();
+    assert_eq!(tags, vec![0, 1, 2, 0, 1]);
+}
+
+#[test]
+fn test_dedup_sorted() {
+    let mut source: Vec<i32> = Vec::new();
+    assert_eq!(dedup_sorted(&mut source), 0);
+
+    let mut source = vec![1, 1, 1];
+    assert_eq!(dedup_sorted(&mut source), 1);
+    assert_eq!(source[0], 1);
+
+    let mut rng = rand::thread_rng();
+    let mut source: Vec<i32> = (0..1000).map(|_| rng.gen_range(0, 100)).collect();
+    source.sort();
+    let mut exp = source.clone();
+    exp.dedup();
+    let len = dedup_sorted(&mut source);
+    assert_eq!(&source[..len], &exp[..]);
+}
+
+#[test]
+fn test_count_inversions() {
+    let empty: [i32; 0] = [];
+    assert_eq!(count_inversions(&empty), 0);
+    assert_eq!(count_inversions(&[1, 2, 3]), 0);
+    assert_eq!(count_inversions(&[3, 2, 1]), 3);
+    assert_eq!(count_inversions(&[1, 1, 1]), 0);
+
+    let mut rng = rand::thread_rng();
+    let source: Vec<i32> = (0..500).map(|_| rng.gen_range(0, 50)).collect();
+    let mut exp = 0;
+    for i in 0..source.len() {
+        for j in i + 1..source.len() {
+            if source[i] > source[j] {
+                exp += 1;
+            }
+        }
+    }
+    assert_eq!(count_inversions(&source), exp);
+
+    let reversed: Vec<u32> = (0..1000).rev().collect();
+    assert_eq!(count_inversions(&reversed), 1000 * 999 / 2);
+}
@@ -0,0 +1,57 @@
+extern crate algorithm;
+extern crate rand;
+
+use algorithm::search::exponential::exponential_search;
+use algorithm::search::fibonacci::fibonacci_search;
+use algorithm::search::interpolation::interpolation_search;
+use algorithm::search::jump::jump_search;
+use rand::Rng;
+
+/// Search of a sorted slice returning the index of a match.
+type Search = fn(&[u32], &u32) -> Option<usize>;
+
+/// The searches checked against the same cases.
+fn searches() -> Vec<(&'static str, Search)> {
+    vec![
+        ("exponential", exponential_search),
+        ("fibonacci", fibonacci_search),
+        ("interpolation", interpolation_search),
+        ("jump", jump_search),
+    ]
+}
+
+#[test]
+fn test_small() {
+    let empty: [u32; 0] = [];
+    for (name, search) in searches() {
+        assert_eq!(search(&empty, &1), None, "{}", name);
+        assert_eq!(search(&[1], &1), Some(0), "{}", name);
+        assert_eq!(search(&[1], &0), None, "{}", name);
+        assert_eq!(search(&[1], &2), None, "{}", name);
+        assert_eq!(search(&[1, 3], &3), Some(1), "{}", name);
+        assert_eq!(search(&[1, 3], &2), None, "{}", name);
+    }
+}
+
+#[test]
+fn test_random() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..200 {
+        let len = rng.gen_range(0, 300);
+        let mut source: Vec<u32> = (0..len).map(|_| rng.gen_range(0, 1000)).collect();
+        source.sort();
+        for (name, search) in searches() {
+            for _ in 0..20 {
+                let target = rng.gen_range(0, 1001);
+                match search(&source, &target) {
+                    Some(i) => assert_eq!(source[i], target, "{}", name),
+                    None => assert!(!source.contains(&target), "{}", name),
+                }
+            }
+            for target in &source {
+                let found = search(&source, target).unwrap();
+                assert_eq!(source[found], *target, "{}", name);
+            }
+        }
+    }
+}
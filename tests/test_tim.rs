@@ -0,0 +1,68 @@
+extern crate algorithm;
+extern crate rand;
+
+mod common;
+
+use algorithm::sort::tim::tim_sort;
+use common::{assert_stable, records, Record};
+use rand::Rng;
+
+fn check(mut source: Vec<i64>) {
+    let mut exp = source.clone();
+    exp.sort();
+    tim_sort(&mut source);
+    assert_eq!(source, exp);
+}
+
+#[test]
+fn test_tim_sort_array() {
+    let mut source = vec![3, 2, 5, 1, 4];
+    tim_sort(&mut source);
+    assert_eq!(source, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_tim_sort_random() {
+    let mut rng = rand::thread_rng();
+    for len in [0, 1, 2, 63, 64, 65, 1000, 20000] {
+        check((0..len).map(|_| rng.gen_range(-1000, 1000)).collect());
+    }
+}
+
+#[test]
+fn test_tim_sort_runs() {
+    check((0..100000).collect());
+    check((0..100000).rev().collect());
+    // ascending and descending runs of various lengths
+    let mut rng = rand::thread_rng();
+    let mut source = Vec::new();
+    while source.len() < 50000 {
+        let len = rng.gen_range(1, 2000);
+        let base = rng.gen_range(-100000, 100000);
+        if rng.gen() {
+            source.extend((0..len).map(|i| base + i));
+        } else {
+            source.extend((0..len).map(|i| base - i));
+        }
+    }
+    check(source);
+}
+
+#[test]
+fn test_tim_sort_stable() {
+    for &(len, keys) in &[(50, 3), (5000, 2), (50000, 100)] {
+        let mut source = records(len, keys);
+        tim_sort(&mut source);
+        assert_stable(&source);
+    }
+
+    // descending runs with equal keys must not be reversed
+    let mut source: Vec<Record> = (0..10000)
+        .map(|i| Record {
+            key: (10000 - i as u32) / 7,
+            payload: i,
+        })
+        .collect();
+    tim_sort(&mut source);
+    assert_stable(&source);
+}
@@ -0,0 +1,175 @@
+extern crate algorithm;
+extern crate rand;
+
+use std::collections::HashMap;
+
+use algorithm::sort::topological::{
+    topological_sort, topological_sort_dfs, topological_sort_lexicographic,
+};
+use rand::Rng;
+
+type Sort = fn(&HashMap<usize, Vec<usize>>) -> Result<Vec<usize>, Vec<usize>>;
+
+fn sorts() -> Vec<Sort> {
+    vec![
+        topological_sort,
+        topological_sort_dfs,
+        topological_sort_lexicographic,
+    ]
+}
+
+/// Random DAG on `n` nodes: edges only go from a lower to a higher label
+/// of a random labelling.
+fn random_dag(n: usize, edges: usize) -> HashMap<usize, Vec<usize>> {
+    let mut rng = rand::thread_rng();
+    let mut label: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut label);
+
+    let mut graph: HashMap<usize, Vec<usize>> = HashMap::new();
+    for node in 0..n {
+        graph.insert(node, Vec::new());
+    }
+    for _ in 0..edges {
+        let a = rng.gen_range(0, n);
+        let b = rng.gen_range(0, n);
+        if label[a] < label[b] {
+            graph.get_mut(&a).unwrap().push(b);
+        }
+    }
+    graph
+}
+
+fn assert_order(graph: &HashMap<usize, Vec<usize>>, order: &[usize]) {
+    let position: HashMap<usize, usize> = order.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+    assert_eq!(position.len(), order.len());
+    for (node, targets) in graph {
+        for target in targets {
+            assert!(position[node] < position[target]);
+        }
+    }
+}
+
+fn assert_cycle(graph: &HashMap<usize, Vec<usize>>, cycle: &[usize]) {
+    assert!(!cycle.is_empty());
+    for i in 0..cycle.len() {
+        let next = cycle[(i + 1) % cycle.len()];
+        assert!(graph[&cycle[i]].contains(&next));
+    }
+}
+
+#[test]
+fn test_empty() {
+    let graph = HashMap::new();
+    for sort in sorts() {
+        assert_eq!(sort(&graph), Ok(vec![]));
+    }
+}
+
+#[test]
+fn test_targets_only() {
+    let mut graph = HashMap::new();
+    graph.insert(1, vec![2, 3]);
+    for sort in sorts() {
+        let order = sort(&graph).unwrap();
+        assert_eq!(order.len(), 3);
+        assert_order(&graph, &order);
+    }
+}
+
+#[test]
+fn test_random_dag() {
+    for _ in 0..20 {
+        let graph = random_dag(200, 600);
+        for sort in sorts() {
+            let order = sort(&graph).unwrap();
+            assert_eq!(order.len(), 200);
+            assert_order(&graph, &order);
+        }
+    }
+}
+
+#[test]
+fn test_cycle() {
+    let mut graph = HashMap::new();
+    graph.insert(0, vec![1]);
+    graph.insert(1, vec![2, 5]);
+    graph.insert(2, vec![3]);
+    graph.insert(3, vec![1]);
+    graph.insert(4, vec![0]);
+    for sort in sorts() {
+        let cycle = sort(&graph).unwrap_err();
+        assert_cycle(&graph, &cycle);
+        assert_eq!(cycle.len(), 3);
+    }
+
+    let mut graph = HashMap::new();
+    graph.insert(7, vec![7]);
+    for sort in sorts() {
+        assert_eq!(sort(&graph), Err(vec![7]));
+    }
+}
+
+#[test]
+fn test_random_cycle() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        let mut graph = random_dag(100, 300);
+        // any edge back from the end of a path closes a cycle
+        let order = topological_sort(&graph).unwrap();
+        let i = rng.gen_range(0, 100);
+        let last = graph[&order[i]]
+            .iter()
+            .cloned()
+            .max_by_key(|n| order.iter().position(|m| m == n));
+        let last = match last {
+            Some(last) => last,
+            None => continue,
+        };
+        graph.get_mut(&last).unwrap().push(order[i]);
+        for sort in sorts() {
+            assert_cycle(&graph, &sort(&graph).unwrap_err());
+        }
+    }
+}
+
+#[test]
+fn test_lexicographic() {
+    let mut graph = HashMap::new();
+    graph.insert(5, vec![0, 2]);
+    graph.insert(4, vec![0, 1]);
+    graph.insert(2, vec![3]);
+    graph.insert(3, vec![1]);
+    assert_eq!(
+        topological_sort_lexicographic(&graph),
+        Ok(vec![4, 5, 0, 2, 3, 1])
+    );
+
+    // the smallest of all valid orders, checked by brute force on small DAGs
+    for _ in 0..50 {
+        let graph = random_dag(6, 8);
+        let mut best: Option<Vec<usize>> = None;
+        let mut perm: Vec<usize> = (0..6).collect();
+        permutations(&mut perm, 0, &mut |p: &[usize]| {
+            let valid = graph.iter().all(|(a, ts)| {
+                ts.iter()
+                    .all(|b| p.iter().position(|x| x == a) < p.iter().position(|x| x == b))
+            });
+            if valid && best.as_ref().is_none_or(|b| &b[..] > p) {
+                best = Some(p.to_vec());
+            }
+        });
+        assert_eq!(topological_sort_lexicographic(&graph).ok(), best);
+    }
+}
+
+fn permutations<F: FnMut(&[usize])>(source: &mut Vec<usize>, k: usize, visit: &mut F) {
+    if k == source.len() {
+        visit(source);
+        return;
+    }
+    for i in k..source.len() {
+        source.swap(k, i);
+        permutations(source, k + 1, visit);
+        source.swap(k, i);
+    }
+}
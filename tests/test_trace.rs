@@ -0,0 +1,105 @@
+extern crate algorithm;
+extern crate serde_json;
+
+use algorithm::tree::avl_tree::AVLTree;
+use algorithm::tree::red_black_tree::RBTree;
+use algorithm::tree::trace::TraceEvent;
+
+#[test]
+fn test_avl_insert_traced() {
+    let mut tree = AVLTree::Empty;
+    tree.insert(3).insert(2);
+    let act = tree.insert_traced(1);
+    let exp = vec![
+        TraceEvent::Visit { value: 3 },
+        TraceEvent::Visit { value: 2 },
+        TraceEvent::UpdateHeight {
+            value: 2,
+            height: 2,
+        },
+        TraceEvent::RotateRight { value: 3 },
+        TraceEvent::UpdateHeight {
+            value: 3,
+            height: 1,
+        },
+        TraceEvent::UpdateHeight {
+            value: 2,
+            height: 2,
+        },
+    ];
+    assert_eq!(act, exp);
+}
+
+#[test]
+fn test_avl_remove_traced_same_as_remove() {
+    let mut traced = AVLTree::Empty;
+    let mut plain = AVLTree::Empty;
+    for v in vec![3, 2, 1, 4, 5, 6, 7, 16, 15, 14, 13, 12, 11, 10, 8, 9] {
+        traced.insert_traced(v);
+        plain.insert(v);
+    }
+    let events = traced.remove_traced(10);
+    traced.remove_traced(12);
+    plain.remove(10).remove(12);
+    assert_eq!(events[0], TraceEvent::Visit { value: 7 });
+    assert_eq!(traced.pre_order(), plain.pre_order());
+}
+
+#[test]
+fn test_rb_insert_traced() {
+    let mut tree = RBTree::new();
+    tree.insert('C').insert('B');
+    let act = tree.insert_traced('A');
+    let exp = vec![
+        TraceEvent::Visit { value: 'C' },
+        TraceEvent::Visit { value: 'B' },
+        TraceEvent::RotateRight { value: 'C' },
+    ];
+    assert_eq!(act, exp);
+}
+
+#[test]
+fn test_rb_remove_traced() {
+    let mut tree = RBTree::new();
+    tree.insert('C')
+        .insert('B')
+        .insert('A')
+        .insert('D')
+        .insert('E');
+    let act = tree.remove_min_traced();
+    assert!(act.contains(&TraceEvent::MoveRedLeft { value: 'B' }));
+
+    let act = tree.remove_max_traced();
+    assert_eq!(act[0], TraceEvent::Visit { value: 'D' });
+
+    let act = tree.remove_traced(&'Z');
+    assert!(act.is_empty());
+
+    let act = tree.remove_traced(&'C');
+    assert!(!act.is_empty());
+    assert!(!tree.contains(&'C'));
+}
+
+#[test]
+fn test_trace_json_round_trip() {
+    let mut tree = RBTree::new();
+    tree.insert('C').insert('B');
+    let events = tree.insert_traced('A');
+
+    let json = serde_json::to_string(&events).unwrap();
+    let replayed: Vec<TraceEvent<char>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(replayed, events);
+}
+
+#[test]
+fn test_trace_event_display() {
+    let event = TraceEvent::UpdateHeight {
+        value: 2,
+        height: 3,
+    };
+    assert_eq!(format!("{}", event), "height of 2 -> 3");
+    assert_eq!(
+        format!("{}", TraceEvent::MoveRedLeft { value: 'B' }),
+        "move red left at B"
+    );
+}
@@ -0,0 +1,80 @@
+extern crate algorithm;
+use algorithm::tree::trie::Trie;
+
+#[test]
+fn test_insert_get() {
+    let mut trie = Trie::new();
+    assert_eq!(trie.insert("tea", 1), None);
+    assert_eq!(trie.insert("ten", 2), None);
+    assert_eq!(trie.insert("tea", 3), Some(1));
+    assert_eq!(trie.len(), 2);
+    assert_eq!(trie.get("tea"), Some(&3));
+    assert_eq!(trie.get("te"), None);
+    assert_eq!(trie.get("team"), None);
+    assert!(trie.contains_key("ten"));
+
+    *trie.get_mut("ten").unwrap() += 10;
+    assert_eq!(trie.get("ten"), Some(&12));
+}
+
+#[test]
+fn test_remove() {
+    let mut trie = Trie::new();
+    trie.insert("in", 1);
+    trie.insert("inn", 2);
+    trie.insert("int", 3);
+    assert_eq!(trie.remove("i"), None);
+    assert_eq!(trie.remove("inn"), Some(2));
+    assert_eq!(trie.remove("inn"), None);
+    assert_eq!(trie.len(), 2);
+    assert_eq!(trie.get("in"), Some(&1));
+    assert_eq!(trie.get("int"), Some(&3));
+
+    trie.remove("in");
+    trie.remove("int");
+    assert!(trie.is_empty());
+    assert_eq!(trie.iter().count(), 0);
+}
+
+#[test]
+fn test_prefix_iter() {
+    let mut trie = Trie::new();
+    for (i, w) in ["to", "tea", "ted", "ten", "i", "in", "inn", "A"].iter().enumerate() {
+        trie.insert(w, i);
+    }
+    let act: Vec<String> = trie.prefix_iter("te").map(|(k, _)| k).collect();
+    assert_eq!(act, vec!["tea", "ted", "ten"]);
+
+    let act: Vec<(String, &usize)> = trie.prefix_iter("in").collect();
+    assert_eq!(act, vec![("in".to_string(), &5), ("inn".to_string(), &6)]);
+
+    let act: Vec<String> = trie.iter().map(|(k, _)| k).collect();
+    assert_eq!(act, vec!["A", "i", "in", "inn", "tea", "ted", "ten", "to"]);
+
+    assert_eq!(trie.prefix_iter("x").count(), 0);
+}
+
+#[test]
+fn test_longest_prefix_match() {
+    let mut trie = Trie::new();
+    trie.insert("192.168", "lan");
+    trie.insert("192.168.1", "office");
+    assert_eq!(trie.longest_prefix_match("192.168.1.20"), Some(("192.168.1", &"office")));
+    assert_eq!(trie.longest_prefix_match("192.168.2.1"), Some(("192.168", &"lan")));
+    assert_eq!(trie.longest_prefix_match("10.0.0.1"), None);
+}
+
+#[test]
+fn test_unicode_keys() {
+    let mut trie = Trie::new();
+    trie.insert("中文", 1);
+    trie.insert("中文测试", 2);
+    trie.insert("中国", 3);
+    trie.insert("héllo", 4);
+
+    let act: Vec<String> = trie.prefix_iter("中").map(|(k, _)| k).collect();
+    assert_eq!(act, vec!["中国", "中文", "中文测试"]);
+    assert_eq!(trie.longest_prefix_match("中文测"), Some(("中文", &1)));
+    assert_eq!(trie.get("hé"), None);
+    assert_eq!(trie.prefix_iter("hé").count(), 1);
+}